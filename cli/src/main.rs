@@ -0,0 +1,374 @@
+// built-ins
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+// libs
+use serde_json::{json, Value};
+use swc_common::{
+    comments::SingleThreadedComments,
+    errors::{Handler, SourceMapper, HANDLER},
+    sync::Lrc,
+    SourceMap, Span,
+};
+use swc_ecma_ast::{EsVersion, Program};
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_parser::{lexer::Lexer, EsConfig, Parser, StringInput, Syntax, TsConfig};
+use swc_ecma_visit::FoldWith;
+
+// helpers
+use graphql_tag::ast::{lower_document, print_document, split_operations};
+use graphql_tag::extract::extract_documents;
+use graphql_tag::parser::parse_graphql_tag;
+use graphql_tag::transform;
+use graphql_tag_swc_plugin::create_config;
+
+// structs
+use graphql_tag::TransformReport;
+
+const USAGE: &str = "Usage: graphql-tag-swc [extract] [options] <files or globs...>
+
+Commands:
+  extract               print every gql document as written, with its operations,
+                        fragment dependencies and interpolations, as JSON
+
+Options:
+  --config <json|path>  plugin config, as a JSON string or a path to a JSON file
+  --out-dir <dir>       write transformed files to <dir> instead of stdout,
+                        required to transform more than one file
+  --check               only report diagnostics, exits with 1 on GraphQL errors
+  --operations          dump the operations of every compiled document as JSON
+  -h, --help            print this message";
+
+#[derive(Default)]
+struct Args {
+    patterns: Vec<String>,
+    config: Option<String>,
+    out_dir: Option<PathBuf>,
+    check: bool,
+    operations: bool,
+    extract: bool,
+}
+
+struct TransformResult {
+    code: String,
+    report: TransformReport,
+}
+
+fn parse_args(raw_args: Vec<String>) -> Result<Args, String> {
+    let mut args = Args::default();
+    let mut raw_args = raw_args.into_iter().peekable();
+    if raw_args.peek().is_some_and(|arg| arg == "extract") {
+        args.extract = true;
+        raw_args.next();
+    }
+
+    while let Some(arg) = raw_args.next() {
+        match arg.as_str() {
+            "--config" => {
+                let value = raw_args.next().ok_or("Missing value for --config")?;
+                args.config = Some(if value.trim_start().starts_with('{') {
+                    value
+                } else {
+                    fs::read_to_string(&value)
+                        .map_err(|error| format!("Could not read config {}: {}", value, error))?
+                });
+            }
+            "--out-dir" => {
+                let value = raw_args.next().ok_or("Missing value for --out-dir")?;
+                args.out_dir = Some(PathBuf::from(value));
+            }
+            "--check" => args.check = true,
+            "--operations" => args.operations = true,
+            _ if arg.starts_with('-') => return Err(format!("Unknown option {}", arg)),
+            _ => args.patterns.push(arg),
+        }
+    }
+
+    if args.patterns.is_empty() {
+        return Err("No input files given".into());
+    }
+
+    Ok(args)
+}
+
+fn get_input_files(patterns: &[String]) -> Result<Vec<PathBuf>, String> {
+    let mut files = vec![];
+    for pattern in patterns {
+        let paths = glob::glob(pattern)
+            .map_err(|error| format!("Got invalid glob {}: {}", pattern, error))?;
+        let count = files.len();
+        for path in paths.flatten() {
+            if path.is_file() && !files.contains(&path) {
+                files.push(path);
+            }
+        }
+        if files.len() == count {
+            return Err(format!("No files matched {}", pattern));
+        }
+    }
+    Ok(files)
+}
+
+fn get_syntax(path: &Path) -> Syntax {
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("ts" | "mts" | "cts") => Syntax::Typescript(TsConfig::default()),
+        Some("tsx") => Syntax::Typescript(TsConfig {
+            tsx: true,
+            ..Default::default()
+        }),
+        _ => Syntax::Es(EsConfig {
+            jsx: true,
+            ..Default::default()
+        }),
+    }
+}
+
+fn get_output_path(out_dir: &Path, path: &Path) -> PathBuf {
+    let relative_path = env::current_dir()
+        .ok()
+        .and_then(|cwd| path.strip_prefix(cwd).ok())
+        .unwrap_or(path);
+    // absolute paths outside of the working directory keep only their name
+    if relative_path.is_absolute() {
+        out_dir.join(relative_path.file_name().unwrap_or_default())
+    } else {
+        out_dir.join(relative_path)
+    }
+}
+
+fn parse_file(path: &Path) -> Result<(Lrc<SourceMap>, SingleThreadedComments, Program), String> {
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm
+        .load_file(path)
+        .map_err(|error| format!("Could not read {}: {}", path.display(), error))?;
+
+    let comments = SingleThreadedComments::default();
+    let lexer = Lexer::new(
+        get_syntax(path),
+        EsVersion::latest(),
+        StringInput::from(&*fm),
+        Some(&comments),
+    );
+    let program = Parser::new_from(lexer)
+        .parse_program()
+        .map_err(|error| format!("Could not parse {}: {}", path.display(), error.kind().msg()))?;
+
+    Ok((cm, comments, program))
+}
+
+fn transform_file(path: &Path, config_str: Option<String>) -> Result<TransformResult, String> {
+    let (cm, comments, program) = parse_file(path)?;
+
+    let options = create_config(config_str, path.display().to_string())?;
+    let mut pass = transform(options, comments.clone());
+    let handler = Handler::with_emitter_writer(Box::new(io::stderr()), Some(cm.clone()));
+    let program = HANDLER.set(&handler, || program.fold_with(&mut pass));
+
+    let mut buf = vec![];
+    {
+        let mut emitter = Emitter {
+            cfg: Default::default(),
+            cm: cm.clone(),
+            comments: Some(&comments),
+            wr: JsWriter::new(cm.clone(), "\n", &mut buf, None),
+        };
+        emitter
+            .emit_program(&program)
+            .map_err(|error| format!("Could not emit {}: {}", path.display(), error))?;
+    }
+
+    Ok(TransformResult {
+        code: String::from_utf8_lossy(&buf).into_owned(),
+        report: pass.take_report(),
+    })
+}
+
+fn get_location(cm: &SourceMap, span: Span) -> Value {
+    let start = cm.lookup_char_pos(span.lo);
+    let end = cm.lookup_char_pos(span.hi);
+    json!({
+        "start": { "line": start.line, "column": start.col_display },
+        "end": { "line": end.line, "column": end.col_display },
+    })
+}
+
+fn extract_file(path: &Path, config_str: Option<String>) -> Result<Vec<Value>, String> {
+    let (cm, _, program) = parse_file(path)?;
+    let options = create_config(config_str, path.display().to_string())?;
+
+    let documents = extract_documents(&program, options.config())
+        .into_iter()
+        .map(|extracted_document| {
+            let operations: Vec<Value> = extracted_document
+                .operations
+                .iter()
+                .map(|operation| {
+                    json!({
+                        "name": operation.name,
+                        "operation": operation.operation.as_str(),
+                    })
+                })
+                .collect();
+            let interpolations: Vec<Value> = extracted_document
+                .interpolations
+                .iter()
+                .map(|interpolation| {
+                    json!({
+                        "location": get_location(&cm, interpolation.span),
+                        "source": cm.span_to_snippet(interpolation.span).ok(),
+                        "identifier": interpolation.identifier,
+                    })
+                })
+                .collect();
+            let errors: Vec<&String> = extracted_document
+                .errors
+                .iter()
+                .map(|error| &error.message)
+                .collect();
+
+            json!({
+                "file": path.display().to_string(),
+                "location": get_location(&cm, extracted_document.span),
+                "declarator": extracted_document.declarator,
+                "text": extracted_document.text,
+                "document": extracted_document.document.as_ref().map(print_document),
+                "operations": operations,
+                "fragments": extracted_document.fragments,
+                "fragmentDependencies": extracted_document.fragment_dependencies,
+                "interpolations": interpolations,
+                "errors": errors,
+            })
+        })
+        .collect();
+
+    Ok(documents)
+}
+
+fn write_json(value: &Value, out_dir: Option<&PathBuf>, file_name: &str) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(value).unwrap();
+    match out_dir {
+        Some(out_dir) => {
+            let output_path = out_dir.join(file_name);
+            fs::create_dir_all(out_dir)
+                .and_then(|_| fs::write(&output_path, json))
+                .map_err(|error| format!("Could not write {}: {}", output_path.display(), error))
+        }
+        None => {
+            println!("{}", json);
+            Ok(())
+        }
+    }
+}
+
+fn run(args: Args) -> Result<bool, String> {
+    let files = get_input_files(&args.patterns)?;
+
+    if args.extract {
+        let mut documents = vec![];
+        for path in &files {
+            documents.extend(extract_file(path, args.config.clone())?);
+        }
+        write_json(
+            &Value::Array(documents),
+            args.out_dir.as_ref(),
+            "documents.json",
+        )?;
+        return Ok(true);
+    }
+
+    // the code of several files can't be told apart on stdout
+    let prints_code = !args.check && !args.operations && args.out_dir.is_none();
+    if prints_code && files.len() > 1 {
+        return Err(format!(
+            "Got {} files, use --out-dir to transform more than one",
+            files.len()
+        ));
+    }
+
+    let mut error_count = 0;
+    let mut operations: Vec<Value> = vec![];
+    for path in &files {
+        let result = transform_file(path, args.config.clone())?;
+        error_count += result.report.error_count;
+
+        if args.operations {
+            for document in &result.report.documents {
+                // compiled documents always parse, failing ones were reported
+                let Ok(document) =
+                    parse_graphql_tag(document).and_then(|document| lower_document(&document))
+                else {
+                    continue;
+                };
+                for (name, operation_document) in split_operations(&document) {
+                    operations.push(json!({
+                        "file": path.display().to_string(),
+                        "name": name,
+                        "document": print_document(&operation_document),
+                    }));
+                }
+            }
+        } else if !args.check {
+            match &args.out_dir {
+                Some(out_dir) => {
+                    let output_path = get_output_path(out_dir, path);
+                    if let Some(parent) = output_path.parent() {
+                        fs::create_dir_all(parent).map_err(|error| {
+                            format!("Could not create {}: {}", parent.display(), error)
+                        })?;
+                    }
+                    fs::write(&output_path, result.code).map_err(|error| {
+                        format!("Could not write {}: {}", output_path.display(), error)
+                    })?;
+                }
+                None => print!("{}", result.code),
+            }
+        }
+    }
+
+    if args.operations {
+        write_json(
+            &Value::Array(operations),
+            args.out_dir.as_ref(),
+            "operations.json",
+        )?;
+    }
+
+    if args.check {
+        eprintln!(
+            "Found {} GraphQL error(s) in {} file(s)",
+            error_count,
+            files.len()
+        );
+        return Ok(error_count == 0);
+    }
+
+    Ok(true)
+}
+
+fn main() -> ExitCode {
+    let raw_args: Vec<String> = env::args().skip(1).collect();
+    if raw_args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    let args = match parse_args(raw_args) {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("graphql-tag-swc: {}\n\n{}", error, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run(args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("graphql-tag-swc: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
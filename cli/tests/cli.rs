@@ -0,0 +1,129 @@
+// built-ins
+use std::{
+    fs,
+    path::PathBuf,
+    process::{Command, Output},
+};
+
+// libs
+use serde_json::Value;
+
+fn run_cli(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_graphql-tag-swc"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn get_out_dir(name: &str) -> PathBuf {
+    let out_dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&out_dir);
+    out_dir
+}
+
+#[test]
+fn prints_transformed_code() {
+    let output = run_cli(&["tests/fixtures/queries.js", "--config", r#"{"strip":true}"#]);
+    let code = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success());
+    assert!(!code.contains("gql`"));
+    assert!(code.contains(r#""kind": "Document""#));
+    assert!(code.contains("query GetUser($id:ID!){user(id:$id){...UserFields}}"));
+}
+
+#[test]
+fn check_fails_on_graphql_errors() {
+    let output = run_cli(&["--check", "tests/fixtures/queries.js"]);
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+
+    let output = run_cli(&["--check", "tests/fixtures/*.js"]);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr.contains("Found 1 GraphQL error(s) in 2 file(s)"));
+}
+
+#[test]
+fn dumps_operations() {
+    let output = run_cli(&["--operations", "tests/fixtures/queries.js"]);
+    let operations: Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert!(output.status.success());
+    let names: Vec<&str> = operations
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|operation| operation["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["GetUser", "RenameUser"]);
+    assert!(operations[0]["document"]
+        .as_str()
+        .unwrap()
+        .contains("fragment UserFields on User"));
+    assert!(!operations[1]["document"]
+        .as_str()
+        .unwrap()
+        .contains("fragment UserFields on User"));
+}
+
+#[test]
+fn requires_out_dir_for_several_files() {
+    let output = run_cli(&["tests/fixtures/*.js"]);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(stderr.contains("use --out-dir"));
+
+    let out_dir = get_out_dir("several_files");
+    let output = run_cli(&[
+        "tests/fixtures/*.js",
+        "--out-dir",
+        out_dir.to_str().unwrap(),
+    ]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(out_dir.join("tests/fixtures/queries.js").is_file());
+    assert!(out_dir.join("tests/fixtures/invalid.js").is_file());
+}
+
+#[test]
+fn check_passes_templates_parsed_at_runtime() {
+    let output = run_cli(&["--check", "tests/fixtures/warnings/dynamic_segments.js"]);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert!(output.status.success());
+    assert!(stderr.contains("warning: expected a Name in Type Condition"));
+    assert!(stderr.contains("Found 0 GraphQL error(s) in 1 file(s)"));
+}
+
+#[test]
+fn check_warns_about_unused_fragments() {
+    let output = run_cli(&["--check", "tests/fixtures/warnings/unused_fragments.js"]);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert!(output.status.success());
+    assert!(stderr.contains("warning: Fragment \"UserEmail\" is never used"));
+    assert!(stderr.contains("warning: Interpolated fragment \"UserAvatar\" is never used"));
+    assert!(!stderr.contains("\"UserFields\" is never used"));
+    assert!(stderr.contains("Found 0 GraphQL error(s) in 1 file(s)"));
+}
+
+#[test]
+fn check_warns_when_types_are_not_generated() {
+    let output = run_cli(&[
+        "--check",
+        "tests/fixtures/typegen/unknown_field.ts",
+        "--config",
+        r#"{"schema":"tests/fixtures/typegen/schema.graphql"}"#,
+    ]);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert!(output.status.success());
+    assert!(stderr.contains(
+        "warning: Types were not generated: unknown field \"nickname\" on type \"User\""
+    ));
+    // the fragment of the second document is interpolated at runtime
+    assert_eq!(stderr.matches("Types were not generated").count(), 1);
+    assert!(stderr.contains("Found 0 GraphQL error(s) in 1 file(s)"));
+}
@@ -0,0 +1,9 @@
+import { gql } from "@apollo/client";
+
+const GET_USER = gql`
+  query GetUser {
+    user(id: ) {
+      id
+    }
+  }
+`;
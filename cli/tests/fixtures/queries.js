@@ -0,0 +1,20 @@
+import { gql } from "@apollo/client";
+
+const GET_USER = gql`
+  query GetUser($id: ID!) {
+    user(id: $id) {
+      ...UserFields
+    }
+  }
+
+  mutation RenameUser($id: ID!, $name: String!) {
+    renameUser(id: $id, name: $name) {
+      id
+    }
+  }
+
+  fragment UserFields on User {
+    id
+    name
+  }
+`;
@@ -0,0 +1,22 @@
+import { gql } from "@apollo/client";
+import { USER_FIELDS } from "./fragments";
+
+const GET_USER = gql`
+  query GetUser($id: ID!) {
+    user(id: $id) {
+      id
+      nickname
+    }
+  }
+`;
+
+// the interpolated fragment is only known at runtime
+const GET_USER_FIELDS = gql`
+  query GetUserFields($id: ID!) {
+    user(id: $id) {
+      ...UserFields
+    }
+  }
+
+  ${USER_FIELDS}
+`;
@@ -0,0 +1,26 @@
+import { gql } from "@apollo/client";
+
+const USER_FRAGMENTS = gql`
+  fragment UserFields on User {
+    id
+    name
+  }
+
+  fragment UserAvatar on User {
+    avatar
+  }
+`;
+
+const GET_USER = gql`
+  query GetUser($id: ID!) {
+    user(id: $id) {
+      ...UserFields
+    }
+  }
+
+  fragment UserEmail on User {
+    email
+  }
+
+  ${USER_FRAGMENTS}
+`;
@@ -1,23 +1,173 @@
+// built-ins
+use std::collections::HashMap;
+
 // libs
 use serde::Deserialize;
+use serde_json::Value;
 use swc_core::plugin::{
+    errors::HANDLER,
     metadata::TransformPluginMetadataContextKind,
     plugin_transform,
     proxies::{PluginCommentsProxy, TransformPluginProgramMetadata},
 };
 use swc_ecma_ast::Program;
-use swc_ecma_visit::{as_folder, FoldWith};
+use swc_ecma_visit::FoldWith;
+
+// helpers
+use graphql_tag::transform;
+use graphql_tag::transform::{TransformFactory, TransformRegistry};
+use graphql_tag::validation::get_name_pattern;
 
 // structs
-use graphql_tag::structs::{GraphQLTagConfig, TransformVisitor};
-use unique_identifier::UniqueIdentifierVisitor;
+use graphql_tag::structs::{
+    CommentTemplates, DirectivesConfig, LimitsConfig, LintConfig, OutputFormat, OutputProfile,
+};
+use graphql_tag::GraphQLTagOptions;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Default, Clone, Copy, PartialEq)]
 #[serde(rename_all = "camelCase")]
+pub enum OnInvalidConfig {
+    #[default]
+    Error,
+    Warn,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct Config {
     import_sources: Option<Vec<String>>,
     gql_tag_identifiers: Option<Vec<String>>,
     strip: Option<bool>,
+    output: Option<OutputFormat>,
+    schema: Option<String>,
+    scalars: Option<HashMap<String, String>>,
+    lint: Option<LintConfig>,
+    directives: Option<DirectivesConfig>,
+    limits: Option<LimitsConfig>,
+    resolve_imports: Option<bool>,
+    prune_unused_fragments: Option<bool>,
+    inline_fragments: Option<bool>,
+    normalize: Option<bool>,
+    server_document: Option<bool>,
+    comment_templates: Option<CommentTemplates>,
+    output_profiles: Option<HashMap<String, OutputProfile>>,
+    transforms: Option<Vec<String>>,
+    on_invalid_config: Option<OnInvalidConfig>,
+}
+
+fn parse_config(config_str: &str) -> Result<Config, (String, OnInvalidConfig)> {
+    let value: Value = serde_json::from_str(config_str)
+        .map_err(|error| (error.to_string(), OnInvalidConfig::default()))?;
+
+    serde_path_to_error::deserialize(&value).map_err(|error| {
+        let path = error.path().to_string();
+        let message = if path == "." {
+            error.inner().to_string()
+        } else {
+            format!("invalid `{}`: {}", path, error.inner())
+        };
+        // the mode can still be valid when the rest of the config isn't
+        let on_invalid_config = value
+            .get("onInvalidConfig")
+            .and_then(|mode| OnInvalidConfig::deserialize(mode).ok())
+            .unwrap_or_default();
+        (message, on_invalid_config)
+    })
+}
+
+fn create_transforms(names: Vec<String>) -> Result<Vec<TransformFactory>, String> {
+    let registry = TransformRegistry::default();
+    names
+        .iter()
+        .map(|name| {
+            registry
+                .get(name)
+                .ok_or_else(|| format!("unknown transform `{}` in `transforms`", name))
+        })
+        .collect()
+}
+
+pub fn create_config(
+    config_str: Option<String>,
+    file_path: String,
+) -> Result<GraphQLTagOptions, String> {
+    let default_options = GraphQLTagOptions::new().file_path(file_path);
+
+    let Some(config_str) = config_str else {
+        return Ok(default_options);
+    };
+
+    let graphql_tag_options = parse_config(&config_str).and_then(|config| {
+        let on_invalid_config = config.on_invalid_config.unwrap_or_default();
+        let transforms = create_transforms(config.transforms.unwrap_or_default())
+            .map_err(|error| (error, on_invalid_config))?;
+        let operation_name_pattern = config
+            .lint
+            .as_ref()
+            .and_then(|lint| lint.operation_name_pattern.as_deref());
+        if let Some(pattern) = operation_name_pattern {
+            get_name_pattern(pattern).map_err(|error| {
+                (
+                    format!("invalid `lint.operationNamePattern`: {}", error),
+                    on_invalid_config,
+                )
+            })?;
+        }
+
+        let default_config = default_options.config();
+        let mut options = default_options
+            .clone()
+            .import_sources(
+                config
+                    .import_sources
+                    .unwrap_or(default_config.import_sources.clone()),
+            )
+            .gql_tag_identifiers(
+                config
+                    .gql_tag_identifiers
+                    .unwrap_or(default_config.gql_tag_identifiers.clone()),
+            )
+            .strip(config.strip.unwrap_or(false))
+            .output(config.output.unwrap_or_default())
+            .scalars(config.scalars.unwrap_or_default())
+            .lint(config.lint.unwrap_or_default())
+            .limits(config.limits.unwrap_or_default())
+            .resolve_imports(config.resolve_imports.unwrap_or(false))
+            .prune_unused_fragments(config.prune_unused_fragments.unwrap_or(false))
+            .inline_fragments(config.inline_fragments.unwrap_or(false))
+            .normalize(config.normalize.unwrap_or(false))
+            .server_document(config.server_document.unwrap_or(false))
+            .comment_templates(config.comment_templates.unwrap_or_default());
+        if let Some(schema) = config.schema {
+            options = options.schema(schema);
+        }
+        if let Some(directives) = config.directives {
+            options = options.directives(directives);
+        }
+        for (import_source, profile) in config.output_profiles.unwrap_or_default() {
+            options = options.output_profile(import_source, profile);
+        }
+        for factory in transforms {
+            options = options.transform(move || factory());
+        }
+
+        Ok(options)
+    });
+
+    match graphql_tag_options {
+        Ok(options) => Ok(options),
+        Err((error, OnInvalidConfig::Warn)) => {
+            println!(
+                "Got invalid config for graphql-tag-swc-plugin, using default config instead: {}",
+                error
+            );
+            Ok(default_options)
+        }
+        Err((error, OnInvalidConfig::Error)) => Err(format!(
+            "Got invalid config for graphql-tag-swc-plugin: {}",
+            error
+        )),
+    }
 }
 
 #[plugin_transform]
@@ -27,55 +177,13 @@ pub fn process_transform(program: Program, data: TransformPluginProgramMetadata)
         file_path = name;
     }
 
-    let mut program = program;
-    let mut unique_visitor = UniqueIdentifierVisitor::new();
-
-    program = program.fold_with(&mut as_folder(&mut unique_visitor));
-
-    let unique_fn_name = if unique_visitor.count > 0 {
-        format!("{}{}", unique_visitor.identifier, unique_visitor.count)
-    } else {
-        unique_visitor.identifier
-    };
-
-    let default_config = GraphQLTagConfig {
-        import_sources: vec!["@apollo/client".to_string(), "graphql-tag".into()],
-        gql_tag_identifiers: vec!["gql".to_string()],
-        strip: false,
-        file_path: String::new(),
-        unique_fn_name: unique_fn_name.clone(),
-        unique_fn_used: false,
-    };
-
-    let config = match data.get_transform_plugin_config() {
-        Some(config_str) => {
-            let plugin_config = serde_json::from_str::<Config>(&config_str);
-            match plugin_config {
-                Ok(config) => GraphQLTagConfig {
-                    import_sources: config
-                        .import_sources
-                        .unwrap_or(default_config.import_sources),
-                    gql_tag_identifiers: config
-                        .gql_tag_identifiers
-                        .unwrap_or(default_config.gql_tag_identifiers),
-                    strip: config.strip.unwrap_or(false),
-                    file_path,
-                    unique_fn_name,
-                    unique_fn_used: false,
-                },
-                Err(_) => {
-                    println!("Got invalid config for graphql-tag-swc-plugin, using default config instead");
-                    default_config
-                }
-            }
+    let options = match create_config(data.get_transform_plugin_config(), file_path) {
+        Ok(options) => options,
+        Err(error) => {
+            HANDLER.with(|handler| handler.err(&error));
+            return program;
         }
-        None => default_config,
     };
 
-    program = program.fold_with(&mut as_folder(TransformVisitor::new(
-        config,
-        PluginCommentsProxy,
-    )));
-
-    program
+    program.fold_with(&mut transform(options, PluginCommentsProxy))
 }
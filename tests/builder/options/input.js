@@ -0,0 +1,10 @@
+import { graphql } from "my-graphql";
+
+const QUERY = graphql`
+  query GetUser {
+    user {
+      id
+      name
+    }
+  }
+`;
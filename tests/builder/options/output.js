@@ -0,0 +1,60 @@
+import { graphql } from "my-graphql";
+const QUERY = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "GetUser"
+            },
+            "directives": [],
+            "variableDefinitions": [],
+            "operation": "query",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "user"
+                        },
+                        "arguments": [],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "id"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                },
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "name"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 28,
+        "source": {
+            "body": "query GetUser{user{id name}}"
+        }
+    }
+};
@@ -0,0 +1,18 @@
+import { gql } from "@apollo/client";
+
+const unique = (items) => [...new Set(items)];
+
+const FRAGMENT = gql`
+  fragment UserFields on User {
+    id
+  }
+`;
+
+const QUERY = gql`
+  query GetUser {
+    user {
+      ...UserFields
+    }
+  }
+  ${FRAGMENT}
+`;
@@ -0,0 +1,109 @@
+import { gql } from "@apollo/client";
+const unique1 = (definitions)=>{
+    const names = {};
+    return definitions.filter((definition)=>{
+        if (definition.kind !== 'FragmentDefinition') {
+            return true;
+        }
+        const name = definition.name.value;
+        if (names[name]) {
+            return false;
+        } else {
+            names[name] = true;
+            return true;
+        }
+    });
+};
+const unique = (items)=>[
+        ...new Set(items)
+    ];
+const FRAGMENT = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "FragmentDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "UserFields"
+            },
+            "directives": [],
+            "typeCondition": {
+                "kind": "NamedType",
+                "name": {
+                    "kind": "Name",
+                    "value": "User"
+                }
+            },
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "id"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 31,
+        "source": {
+            "body": "fragment UserFields on User{id}"
+        }
+    }
+};
+const QUERY = {
+    "kind": "Document",
+    "definitions": /*#__PURE__*/ unique1(/*#__PURE__*/ [
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "GetUser"
+            },
+            "directives": [],
+            "variableDefinitions": [],
+            "operation": "query",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "user"
+                        },
+                        "arguments": [],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "FragmentSpread",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "UserFields"
+                                    },
+                                    "directives": []
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }
+    ].concat(FRAGMENT.definitions)),
+    "loc": {
+        "start": 0,
+        "end": 34,
+        "source": {
+            "body": "query GetUser{user{...UserFields}}"
+        }
+    }
+};
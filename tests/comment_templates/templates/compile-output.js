@@ -0,0 +1,226 @@
+import { request } from "graphql-request";
+const unique = (definitions)=>{
+    const names = {};
+    return definitions.filter((definition)=>{
+        if (definition.kind !== 'FragmentDefinition') {
+            return true;
+        }
+        const name = definition.name.value;
+        if (names[name]) {
+            return false;
+        } else {
+            names[name] = true;
+            return true;
+        }
+    });
+};
+import { gql } from "@apollo/client";
+const USER_QUERY = /* GraphQL */ {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "GetUser"
+            },
+            "directives": [],
+            "variableDefinitions": [],
+            "operation": "query",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "user"
+                        },
+                        "arguments": [],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "id"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                },
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "name"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 89,
+        "source": {
+            "body": "\n  # fetches the current user\n  query GetUser {\n    user {\n      id\n      name\n    }\n  }\n"
+        }
+    }
+};
+const USER_FIELDS = /* GraphQL */ {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "FragmentDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "UserFields"
+            },
+            "directives": [],
+            "typeCondition": {
+                "kind": "NamedType",
+                "name": {
+                    "kind": "Name",
+                    "value": "User"
+                }
+            },
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "id"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    },
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "name"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 53,
+        "source": {
+            "body": "\n  fragment UserFields on User {\n    id\n    name\n  }\n"
+        }
+    }
+};
+const FRIENDS_QUERY = {
+    "kind": "Document",
+    "definitions": /*#__PURE__*/ unique(/*#__PURE__*/ [
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "GetFriends"
+            },
+            "directives": [],
+            "variableDefinitions": [],
+            "operation": "query",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "friends"
+                        },
+                        "arguments": [],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "FragmentSpread",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "UserFields"
+                                    },
+                                    "directives": []
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }
+    ].concat(USER_FIELDS.definitions)),
+    "loc": {
+        "start": 0,
+        "end": 69,
+        "source": {
+            "body": "\n  query GetFriends {\n    friends {\n      ...UserFields\n    }\n  }\n  \n"
+        }
+    }
+};
+const SEARCH_QUERY = /* GraphQL */ `query Search{search(first:${10}){id}}`;
+fetch("/graphql", {
+    method: "POST",
+    body: JSON.stringify({
+        query: /* GraphQL */ {
+            "kind": "Document",
+            "definitions": [
+                {
+                    "kind": "OperationDefinition",
+                    "name": {
+                        "kind": "Name",
+                        "value": "Logout"
+                    },
+                    "directives": [],
+                    "variableDefinitions": [],
+                    "operation": "mutation",
+                    "selectionSet": {
+                        "kind": "SelectionSet",
+                        "selections": [
+                            {
+                                "kind": "Field",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "logout"
+                                },
+                                "arguments": [],
+                                "directives": []
+                            }
+                        ]
+                    }
+                }
+            ],
+            "loc": {
+                "start": 0,
+                "end": 52,
+                "source": {
+                    "body": "\n      mutation Logout {\n        logout\n      }\n    "
+                }
+            }
+        }
+    })
+});
+// not GraphQL, left as is
+const INVALID = /* GraphQL */ `
+  SELECT * FROM users;
+`;
+const UNMARKED = `
+  query Unmarked {
+    user
+  }
+`;
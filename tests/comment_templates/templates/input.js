@@ -0,0 +1,58 @@
+import { request } from "graphql-request";
+import { gql } from "@apollo/client";
+
+const USER_QUERY = /* GraphQL */ `
+  # fetches the current user
+  query GetUser {
+    user {
+      id
+      name
+    }
+  }
+`;
+
+const USER_FIELDS = /* GraphQL */ `
+  fragment UserFields on User {
+    id
+    name
+  }
+`;
+
+const FRIENDS_QUERY = gql`
+  query GetFriends {
+    friends {
+      ...UserFields
+    }
+  }
+  ${USER_FIELDS}
+`;
+
+const SEARCH_QUERY = /* GraphQL */ `
+  query Search {
+    search(first: ${10}) {
+      id
+    }
+  }
+`;
+
+fetch("/graphql", {
+  method: "POST",
+  body: JSON.stringify({
+    query: /* GraphQL */ `
+      mutation Logout {
+        logout
+      }
+    `,
+  }),
+});
+
+// not GraphQL, left as is
+const INVALID = /* GraphQL */ `
+  SELECT * FROM users;
+`;
+
+const UNMARKED = `
+  query Unmarked {
+    user
+  }
+`;
@@ -0,0 +1,84 @@
+import { request } from "graphql-request";
+const unique = (definitions)=>{
+    const names = {};
+    return definitions.filter((definition)=>{
+        if (definition.kind !== 'FragmentDefinition') {
+            return true;
+        }
+        const name = definition.name.value;
+        if (names[name]) {
+            return false;
+        } else {
+            names[name] = true;
+            return true;
+        }
+    });
+};
+import { gql } from "@apollo/client";
+const USER_QUERY = /* GraphQL */ `query GetUser{user{id name}}`;
+const USER_FIELDS = /* GraphQL */ `fragment UserFields on User{id name}`;
+const FRIENDS_QUERY = {
+    "kind": "Document",
+    "definitions": /*#__PURE__*/ unique(/*#__PURE__*/ [
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "GetFriends"
+            },
+            "directives": [],
+            "variableDefinitions": [],
+            "operation": "query",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "friends"
+                        },
+                        "arguments": [],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "FragmentSpread",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "UserFields"
+                                    },
+                                    "directives": []
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }
+    ].concat(USER_FIELDS.definitions)),
+    "loc": {
+        "start": 0,
+        "end": 69,
+        "source": {
+            "body": "\n  query GetFriends {\n    friends {\n      ...UserFields\n    }\n  }\n  \n"
+        }
+    }
+};
+const SEARCH_QUERY = /* GraphQL */ `query Search{search(first:${10}){id}}`;
+fetch("/graphql", {
+    method: "POST",
+    body: JSON.stringify({
+        query: /* GraphQL */ `mutation Logout{logout}`
+    })
+});
+// not GraphQL, left as is
+const INVALID = /* GraphQL */ `
+  SELECT * FROM users;
+`;
+const UNMARKED = `
+  query Unmarked {
+    user
+  }
+`;
@@ -0,0 +1,41 @@
+import { gql } from "@apollo/client";
+import { USER_FIELDS } from "./fragments";
+
+const AVATAR_FIELDS = gql`
+  fragment AvatarFields on User {
+    avatar
+  }
+`;
+
+export const GET_USER = gql`
+  query GetUser($id: ID!) {
+    user(id: $id) {
+      ...UserFields
+      ...AvatarFields
+      friends {
+        ...FriendFields
+      }
+    }
+  }
+
+  fragment FriendFields on User {
+    name
+  }
+
+  ${USER_FIELDS}
+  ${AVATAR_FIELDS}
+`;
+
+client.mutate({
+  mutation: gql`
+    mutation {
+      logout
+    }
+    ${getFragment()}
+  `,
+});
+
+const BROKEN = gql`
+  query Broken {
+    user {
+`;
@@ -0,0 +1,10 @@
+import { gql } from "graphql-tag";
+
+// cooked, the argument is a valid string containing quotes
+const SEARCH = gql`
+  query Search {
+    search(text: "say \\"hi\\"") {
+      id
+    }
+  }
+`;
@@ -0,0 +1,14 @@
+import { gql } from "some-other-library";
+import graphql from "graphql-tag";
+
+const IGNORED = gql`
+  query Ignored {
+    user
+  }
+`;
+
+const DEFAULT_IMPORT = graphql`
+  subscription OnMessage {
+    message
+  }
+`;
@@ -1,14 +1,25 @@
 // built-ins
-use std::{fs, path::PathBuf};
+use std::{collections::HashMap, fs, path::PathBuf};
 
 // libs
 use swc_core::ecma::transforms::testing::{test_fixture, FixtureTestConfig, Tester};
-use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_ast::Program;
+use swc_ecma_parser::{EsConfig, Syntax, TsConfig};
 use swc_ecma_visit::as_folder;
 use testing::{fixture, NormalizedOutput};
 
+// helpers
+use graphql_tag::extract::extract_documents;
+use graphql_tag::transform;
+use graphql_tag_swc_plugin::create_config;
+
 // structs
-use graphql_tag::structs::{GraphQLTagConfig, TransformVisitor};
+use graphql_tag::structs::{
+    CommentTemplates, DirectivesConfig, GraphQLTagConfig, LimitsConfig, LintConfig, OutputFormat,
+    OutputProfile, TransformVisitor,
+};
+use graphql_tag::transform::TransformRegistry;
+use graphql_tag::GraphQLTagOptions;
 use unique_identifier::UniqueIdentifierVisitor;
 
 fn get_syntax() -> Syntax {
@@ -33,9 +44,8 @@ fn graphql_tag_fixture(input: PathBuf) {
                     import_sources: vec!["@apollo/client".to_string(), "graphql-tag".into()],
                     gql_tag_identifiers: vec!["gql".to_string()],
                     strip: false,
-                    unique_fn_name: "unique".into(),
-                    unique_fn_used: false,
                     file_path: input.to_str().unwrap().into(),
+                    ..Default::default()
                 },
                 _tr.comments.clone(),
             ))
@@ -57,9 +67,8 @@ fn graphql_tag_fixture(input: PathBuf) {
                     import_sources: vec!["@apollo/client".to_string(), "graphql-tag".into()],
                     gql_tag_identifiers: vec!["gql".to_string()],
                     strip: true,
-                    unique_fn_name: "unique".into(),
-                    unique_fn_used: false,
                     file_path: input.to_str().unwrap().into(),
+                    ..Default::default()
                 },
                 _tr.comments.clone(),
             ))
@@ -73,6 +82,290 @@ fn graphql_tag_fixture(input: PathBuf) {
     );
 }
 
+#[fixture("tests/output_json/**/input.js")]
+fn output_json_fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let output = dir.join("output.js");
+
+    test_fixture(
+        get_syntax(),
+        &|_tr| {
+            as_folder(TransformVisitor::new(
+                GraphQLTagConfig {
+                    output: OutputFormat::Json,
+                    file_path: input.to_str().unwrap().into(),
+                    ..Default::default()
+                },
+                _tr.comments.clone(),
+            ))
+        },
+        &input,
+        &output,
+        FixtureTestConfig {
+            allow_error: true,
+            sourcemap: false,
+        },
+    );
+}
+
+#[fixture("tests/typed_document_node/**/input.ts")]
+fn typed_document_node_fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let output = dir.join("output.ts");
+    let schema = match dir.join("schema.graphql") {
+        schema if schema.exists() => schema,
+        _ => dir.parent().unwrap().join("schema.graphql"),
+    };
+
+    test_fixture(
+        Syntax::Typescript(TsConfig::default()),
+        &|_tr| {
+            as_folder(TransformVisitor::new(
+                GraphQLTagConfig {
+                    schema: Some(schema.to_str().unwrap().into()),
+                    scalars: HashMap::from([("DateTime".to_string(), "string".to_string())]),
+                    file_path: input.to_str().unwrap().into(),
+                    ..Default::default()
+                },
+                _tr.comments.clone(),
+            ))
+        },
+        &input,
+        &output,
+        FixtureTestConfig {
+            allow_error: true,
+            sourcemap: false,
+        },
+    );
+}
+
+#[fixture("tests/lint/**/input.js")]
+fn lint_fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let output = dir.join("output.js");
+
+    test_fixture(
+        get_syntax(),
+        &|_tr| {
+            as_folder(TransformVisitor::new(
+                GraphQLTagConfig {
+                    lint: LintConfig {
+                        require_operation_name: true,
+                        operation_name_pattern: Some("PascalCase".into()),
+                        unique_operation_names: true,
+                        fragment_name_matches_type: true,
+                    },
+                    directives: Some(DirectivesConfig {
+                        deny: vec!["@live".into()],
+                        ..Default::default()
+                    }),
+                    limits: LimitsConfig {
+                        max_depth: Some(3),
+                        max_aliases: Some(1),
+                        max_field_count: Some(7),
+                        complexity: HashMap::from([("search".into(), 5)]),
+                    },
+                    file_path: input.to_str().unwrap().into(),
+                    ..Default::default()
+                },
+                _tr.comments.clone(),
+            ))
+        },
+        &input,
+        &output,
+        FixtureTestConfig {
+            allow_error: true,
+            sourcemap: false,
+        },
+    );
+}
+
+#[fixture("tests/resolve_imports/**/input.js")]
+fn resolve_imports_fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let output = dir.join("output.js");
+
+    test_fixture(
+        get_syntax(),
+        &|_tr| {
+            as_folder(TransformVisitor::new(
+                GraphQLTagConfig {
+                    resolve_imports: true,
+                    file_path: input.to_str().unwrap().into(),
+                    ..Default::default()
+                },
+                _tr.comments.clone(),
+            ))
+        },
+        &input,
+        &output,
+        FixtureTestConfig {
+            allow_error: true,
+            sourcemap: false,
+        },
+    );
+}
+
+#[fixture("tests/prune_unused_fragments/**/input.js")]
+fn prune_unused_fragments_fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let output = dir.join("output.js");
+
+    test_fixture(
+        get_syntax(),
+        &|_tr| {
+            as_folder(TransformVisitor::new(
+                GraphQLTagConfig {
+                    strip: true,
+                    prune_unused_fragments: true,
+                    ..Default::default()
+                },
+                _tr.comments.clone(),
+            ))
+        },
+        &input,
+        &output,
+        FixtureTestConfig {
+            allow_error: true,
+            sourcemap: false,
+        },
+    );
+}
+
+#[fixture("tests/inline_fragments/**/input.js")]
+fn inline_fragments_fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let output = dir.join("output.js");
+
+    test_fixture(
+        get_syntax(),
+        &|_tr| {
+            as_folder(TransformVisitor::new(
+                GraphQLTagConfig {
+                    strip: true,
+                    inline_fragments: true,
+                    ..Default::default()
+                },
+                _tr.comments.clone(),
+            ))
+        },
+        &input,
+        &output,
+        FixtureTestConfig {
+            allow_error: true,
+            sourcemap: false,
+        },
+    );
+}
+
+#[fixture("tests/normalize/**/input.js")]
+fn normalize_fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let output = dir.join("output.js");
+
+    test_fixture(
+        get_syntax(),
+        &|_tr| {
+            as_folder(TransformVisitor::new(
+                GraphQLTagConfig {
+                    normalize: true,
+                    ..Default::default()
+                },
+                _tr.comments.clone(),
+            ))
+        },
+        &input,
+        &output,
+        FixtureTestConfig {
+            allow_error: true,
+            sourcemap: false,
+        },
+    );
+}
+
+#[fixture("tests/server_document/**/input.js")]
+fn server_document_fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let output = dir.join("output.js");
+
+    test_fixture(
+        get_syntax(),
+        &|_tr| {
+            as_folder(TransformVisitor::new(
+                GraphQLTagConfig {
+                    strip: true,
+                    server_document: true,
+                    ..Default::default()
+                },
+                _tr.comments.clone(),
+            ))
+        },
+        &input,
+        &output,
+        FixtureTestConfig {
+            allow_error: true,
+            sourcemap: false,
+        },
+    );
+}
+
+#[fixture("tests/transforms/**/input.js")]
+fn transforms_fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let output = dir.join("output.js");
+
+    test_fixture(
+        get_syntax(),
+        &|_tr| {
+            let registry = TransformRegistry::default();
+            as_folder(TransformVisitor::new(
+                GraphQLTagConfig {
+                    transforms: ["removeClientFields", "addTypename", "pruneUnusedFragments"]
+                        .into_iter()
+                        .filter_map(|name| registry.get(name))
+                        .collect(),
+                    file_path: input.to_str().unwrap().into(),
+                    ..Default::default()
+                },
+                _tr.comments.clone(),
+            ))
+        },
+        &input,
+        &output,
+        FixtureTestConfig {
+            allow_error: true,
+            sourcemap: false,
+        },
+    );
+}
+
+#[fixture("tests/graphql_modules/**/input.js")]
+fn graphql_modules_fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let output = dir.join("output.js");
+    // virtual module wrapping the text of `document.graphql`
+    let file_path = dir.join("document.graphql");
+
+    test_fixture(
+        get_syntax(),
+        &|_tr| {
+            as_folder(TransformVisitor::new(
+                GraphQLTagConfig {
+                    file_path: file_path.to_str().unwrap().into(),
+                    ..Default::default()
+                },
+                _tr.comments.clone(),
+            ))
+        },
+        &input,
+        &output,
+        FixtureTestConfig {
+            allow_error: true,
+            sourcemap: false,
+        },
+    );
+}
+
 #[fixture("tests/unique_identifier/**/input.js")]
 fn unique_identifier_fixture(input: PathBuf) {
     let dir = input.parent().unwrap();
@@ -103,3 +396,198 @@ fn unique_identifier_fixture(input: PathBuf) {
             .unwrap();
     }
 }
+
+#[fixture("tests/extract/**/input.js")]
+fn extract_fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let output = dir.join("output.txt");
+
+    let expected: Result<String, std::io::Error> = fs::read_to_string(&output);
+    let expected = expected.unwrap_or_default();
+
+    let input_text = fs::read_to_string(input).unwrap();
+    let actual_output = Tester::run(|tester| {
+        let module =
+            tester.with_parser("input.js", get_syntax(), &input_text, |p| p.parse_module())?;
+        let documents = extract_documents(&Program::Module(module), &Default::default());
+
+        Ok(documents
+            .iter()
+            .map(|document| {
+                let operations: Vec<String> = document
+                    .operations
+                    .iter()
+                    .map(|operation| {
+                        format!(
+                            "{} {}",
+                            operation.operation.as_str(),
+                            operation.name.as_deref().unwrap_or("<anonymous>")
+                        )
+                    })
+                    .collect();
+                let interpolations: Vec<String> = document
+                    .interpolations
+                    .iter()
+                    .map(|interpolation| {
+                        interpolation
+                            .identifier
+                            .clone()
+                            .unwrap_or("<expression>".into())
+                    })
+                    .collect();
+                let errors: Vec<&str> = document
+                    .errors
+                    .iter()
+                    .map(|error| error.message.as_str())
+                    .collect();
+
+                format!(
+                    "declarator: {}\noperations: {}\nfragments: {}\nfragment dependencies: {}\ninterpolations: {}\nerrors: {}\n",
+                    document.declarator.as_deref().unwrap_or("<none>"),
+                    operations.join(", "),
+                    document.fragments.join(", "),
+                    document.fragment_dependencies.join(", "),
+                    interpolations.join(", "),
+                    errors.join(", ")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"))
+    });
+
+    if actual_output != expected {
+        NormalizedOutput::from(actual_output)
+            .compare_to_file(output)
+            .unwrap();
+    }
+}
+
+#[fixture("tests/builder/**/input.js")]
+fn builder_fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let output = dir.join("output.js");
+
+    test_fixture(
+        get_syntax(),
+        &|_tr| {
+            transform(
+                GraphQLTagOptions::new()
+                    .import_sources(vec!["@apollo/client".into(), "my-graphql".into()])
+                    .gql_tag_identifiers(vec!["gql".into(), "graphql".into()])
+                    .strip(true)
+                    .file_path(input.to_str().unwrap()),
+                _tr.comments.clone(),
+            )
+        },
+        &input,
+        &output,
+        FixtureTestConfig {
+            allow_error: true,
+            sourcemap: false,
+        },
+    );
+
+    // the same pass compiles every program it folds
+    let input_text = fs::read_to_string(&input).unwrap();
+    Tester::run(|tester| {
+        let comments = tester.comments.clone();
+        let mut pass = transform(
+            GraphQLTagOptions::new().file_path(input.to_str().unwrap()),
+            comments.clone(),
+        );
+        let first = tester.apply_transform(&mut pass, "first.js", get_syntax(), &input_text)?;
+        let second = tester.apply_transform(&mut pass, "second.js", get_syntax(), &input_text)?;
+
+        assert_eq!(
+            tester.print(&first, &comments),
+            tester.print(&second, &comments)
+        );
+        assert_ne!(tester.print(&second, &comments), input_text);
+        Ok(())
+    });
+}
+
+#[fixture("tests/config/**/config.json")]
+fn config_fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let output = dir.join("output.txt");
+
+    let expected: Result<String, std::io::Error> = fs::read_to_string(&output);
+    let expected = expected.unwrap_or_default();
+
+    let config_str = fs::read_to_string(&input).unwrap();
+    let actual_output = match create_config(Some(config_str), "input.js".into()) {
+        Ok(options) => format!(
+            "strip: {}\nimport sources: {}\ntransforms: {}",
+            options.config().strip,
+            options.config().import_sources.join(", "),
+            options.config().transforms.len()
+        ),
+        Err(error) => error,
+    };
+
+    if actual_output != expected {
+        NormalizedOutput::from(actual_output)
+            .compare_to_file(output)
+            .unwrap();
+    }
+}
+
+#[fixture("tests/comment_templates/**/input.js")]
+fn comment_templates_fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+
+    for (comment_templates, output) in [
+        (CommentTemplates::Strip, dir.join("strip-output.js")),
+        (CommentTemplates::Compile, dir.join("compile-output.js")),
+    ] {
+        test_fixture(
+            get_syntax(),
+            &|_tr| {
+                as_folder(TransformVisitor::new(
+                    GraphQLTagConfig {
+                        comment_templates,
+                        file_path: input.to_str().unwrap().into(),
+                        ..Default::default()
+                    },
+                    _tr.comments.clone(),
+                ))
+            },
+            &input,
+            &output,
+            FixtureTestConfig {
+                allow_error: true,
+                sourcemap: false,
+            },
+        );
+    }
+}
+
+#[fixture("tests/output_profiles/**/input.js")]
+fn output_profiles_fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let output = dir.join("output.js");
+
+    test_fixture(
+        get_syntax(),
+        &|_tr| {
+            as_folder(TransformVisitor::new(
+                GraphQLTagConfig {
+                    output_profiles: HashMap::from([
+                        ("graphql-request".into(), OutputProfile::String),
+                        ("urql".into(), OutputProfile::Urql),
+                    ]),
+                    file_path: input.to_str().unwrap().into(),
+                    ..Default::default()
+                },
+                _tr.comments.clone(),
+            ))
+        },
+        &input,
+        &output,
+        FixtureTestConfig {
+            allow_error: true,
+            sourcemap: false,
+        },
+    );
+}
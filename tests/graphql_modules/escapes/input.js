@@ -0,0 +1,7 @@
+export default `# compiled like the \`gql\` tag would
+query GetViewer {
+  viewer {
+    id
+  }
+}
+`;
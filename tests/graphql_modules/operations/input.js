@@ -0,0 +1,14 @@
+export default `#import "./UserFields.graphql"
+
+query GetUser($id: ID!) {
+  user(id: $id) {
+    ...UserFields
+  }
+}
+
+mutation RenameUser($id: ID!, $name: String!) {
+  renameUser(id: $id, name: $name) {
+    id
+  }
+}
+`;
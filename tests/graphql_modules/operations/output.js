@@ -0,0 +1,480 @@
+export default {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "GetUser"
+            },
+            "directives": [],
+            "variableDefinitions": [
+                {
+                    "kind": "VariableDefinition",
+                    "directives": [],
+                    "variable": {
+                        "kind": "Variable",
+                        "name": {
+                            "kind": "Name",
+                            "value": "id"
+                        }
+                    },
+                    "type": {
+                        "kind": "NonNullType",
+                        "type": {
+                            "kind": "NamedType",
+                            "name": {
+                                "kind": "Name",
+                                "value": "ID"
+                            }
+                        }
+                    }
+                }
+            ],
+            "operation": "query",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "user"
+                        },
+                        "arguments": [
+                            {
+                                "kind": "Argument",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "id"
+                                },
+                                "value": {
+                                    "kind": "Variable",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "id"
+                                    }
+                                }
+                            }
+                        ],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "FragmentSpread",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "UserFields"
+                                    },
+                                    "directives": []
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        },
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "RenameUser"
+            },
+            "directives": [],
+            "variableDefinitions": [
+                {
+                    "kind": "VariableDefinition",
+                    "directives": [],
+                    "variable": {
+                        "kind": "Variable",
+                        "name": {
+                            "kind": "Name",
+                            "value": "id"
+                        }
+                    },
+                    "type": {
+                        "kind": "NonNullType",
+                        "type": {
+                            "kind": "NamedType",
+                            "name": {
+                                "kind": "Name",
+                                "value": "ID"
+                            }
+                        }
+                    }
+                },
+                {
+                    "kind": "VariableDefinition",
+                    "directives": [],
+                    "variable": {
+                        "kind": "Variable",
+                        "name": {
+                            "kind": "Name",
+                            "value": "name"
+                        }
+                    },
+                    "type": {
+                        "kind": "NonNullType",
+                        "type": {
+                            "kind": "NamedType",
+                            "name": {
+                                "kind": "Name",
+                                "value": "String"
+                            }
+                        }
+                    }
+                }
+            ],
+            "operation": "mutation",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "renameUser"
+                        },
+                        "arguments": [
+                            {
+                                "kind": "Argument",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "id"
+                                },
+                                "value": {
+                                    "kind": "Variable",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "id"
+                                    }
+                                }
+                            },
+                            {
+                                "kind": "Argument",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "name"
+                                },
+                                "value": {
+                                    "kind": "Variable",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "name"
+                                    }
+                                }
+                            }
+                        ],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "id"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        },
+        {
+            "kind": "FragmentDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "UserFields"
+            },
+            "directives": [],
+            "typeCondition": {
+                "kind": "NamedType",
+                "name": {
+                    "kind": "Name",
+                    "value": "User"
+                }
+            },
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "id"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    },
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "name"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 211,
+        "source": {
+            "body": "query GetUser($id: ID!) {\n  user(id: $id) {\n    ...UserFields\n  }\n}\n\nmutation RenameUser($id: ID!, $name: String!) {\n  renameUser(id: $id, name: $name) {\n    id\n  }\n}\n\nfragment UserFields on User {\n  id\n  name\n}"
+        }
+    }
+};
+export const GetUser = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "GetUser"
+            },
+            "directives": [],
+            "variableDefinitions": [
+                {
+                    "kind": "VariableDefinition",
+                    "directives": [],
+                    "variable": {
+                        "kind": "Variable",
+                        "name": {
+                            "kind": "Name",
+                            "value": "id"
+                        }
+                    },
+                    "type": {
+                        "kind": "NonNullType",
+                        "type": {
+                            "kind": "NamedType",
+                            "name": {
+                                "kind": "Name",
+                                "value": "ID"
+                            }
+                        }
+                    }
+                }
+            ],
+            "operation": "query",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "user"
+                        },
+                        "arguments": [
+                            {
+                                "kind": "Argument",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "id"
+                                },
+                                "value": {
+                                    "kind": "Variable",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "id"
+                                    }
+                                }
+                            }
+                        ],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "FragmentSpread",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "UserFields"
+                                    },
+                                    "directives": []
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        },
+        {
+            "kind": "FragmentDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "UserFields"
+            },
+            "directives": [],
+            "typeCondition": {
+                "kind": "NamedType",
+                "name": {
+                    "kind": "Name",
+                    "value": "User"
+                }
+            },
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "id"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    },
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "name"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 112,
+        "source": {
+            "body": "query GetUser($id: ID!) {\n  user(id: $id) {\n    ...UserFields\n  }\n}\n\nfragment UserFields on User {\n  id\n  name\n}"
+        }
+    }
+};
+export const RenameUser = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "RenameUser"
+            },
+            "directives": [],
+            "variableDefinitions": [
+                {
+                    "kind": "VariableDefinition",
+                    "directives": [],
+                    "variable": {
+                        "kind": "Variable",
+                        "name": {
+                            "kind": "Name",
+                            "value": "id"
+                        }
+                    },
+                    "type": {
+                        "kind": "NonNullType",
+                        "type": {
+                            "kind": "NamedType",
+                            "name": {
+                                "kind": "Name",
+                                "value": "ID"
+                            }
+                        }
+                    }
+                },
+                {
+                    "kind": "VariableDefinition",
+                    "directives": [],
+                    "variable": {
+                        "kind": "Variable",
+                        "name": {
+                            "kind": "Name",
+                            "value": "name"
+                        }
+                    },
+                    "type": {
+                        "kind": "NonNullType",
+                        "type": {
+                            "kind": "NamedType",
+                            "name": {
+                                "kind": "Name",
+                                "value": "String"
+                            }
+                        }
+                    }
+                }
+            ],
+            "operation": "mutation",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "renameUser"
+                        },
+                        "arguments": [
+                            {
+                                "kind": "Argument",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "id"
+                                },
+                                "value": {
+                                    "kind": "Variable",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "id"
+                                    }
+                                }
+                            },
+                            {
+                                "kind": "Argument",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "name"
+                                },
+                                "value": {
+                                    "kind": "Variable",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "name"
+                                    }
+                                }
+                            }
+                        ],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "id"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 97,
+        "source": {
+            "body": "mutation RenameUser($id: ID!, $name: String!) {\n  renameUser(id: $id, name: $name) {\n    id\n  }\n}"
+        }
+    }
+};
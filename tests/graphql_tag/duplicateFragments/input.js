@@ -0,0 +1,81 @@
+import { gql } from "@apollo/client";
+
+const FRAGMENT = gql`
+  fragment EntityFields on Entity {
+    id
+  }
+`;
+
+const SAME_FRAGMENT = gql`
+  fragment EntityFields on Entity {
+    id
+  }
+`;
+
+const DIFFERENT_FRAGMENT = gql`
+  fragment EntityFields on Entity {
+    id
+    name
+  }
+`;
+
+// should compile, both fragments have the same selections
+const QUERY = gql`
+  query testQuery {
+    getEntity {
+      ...EntityFields
+    }
+  }
+
+  ${FRAGMENT}
+  ${SAME_FRAGMENT}
+`;
+
+// should not compile, fragments have different selections
+const CONFLICTING_QUERY = gql`
+  query testQuery {
+    getEntity {
+      ...EntityFields
+    }
+  }
+
+  ${FRAGMENT}
+  ${DIFFERENT_FRAGMENT}
+`;
+
+// should not compile, fragment conflicts with interpolated one
+const CONFLICTING_DEFINITION = gql`
+  query testQuery {
+    getEntity {
+      ...EntityFields
+    }
+  }
+
+  fragment EntityFields on Entity {
+    name
+  }
+
+  ${FRAGMENT}
+`;
+
+const NESTED_FRAGMENT = gql`
+  fragment NestedFields on Entity {
+    nested {
+      ...EntityFields
+    }
+  }
+
+  ${FRAGMENT}
+`;
+
+// should not compile, the conflicting fragment is spread through another one
+const CONFLICTING_NESTED_QUERY = gql`
+  query testQuery {
+    getEntity {
+      ...NestedFields
+    }
+  }
+
+  ${NESTED_FRAGMENT}
+  ${DIFFERENT_FRAGMENT}
+`;
@@ -0,0 +1,288 @@
+import { gql } from "@apollo/client";
+const unique = (definitions)=>{
+    const names = {};
+    return definitions.filter((definition)=>{
+        if (definition.kind !== 'FragmentDefinition') {
+            return true;
+        }
+        const name = definition.name.value;
+        if (names[name]) {
+            return false;
+        } else {
+            names[name] = true;
+            return true;
+        }
+    });
+};
+const FRAGMENT = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "FragmentDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "EntityFields"
+            },
+            "directives": [],
+            "typeCondition": {
+                "kind": "NamedType",
+                "name": {
+                    "kind": "Name",
+                    "value": "Entity"
+                }
+            },
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "id"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 48,
+        "source": {
+            "body": "\n  fragment EntityFields on Entity {\n    id\n  }\n"
+        }
+    }
+};
+const SAME_FRAGMENT = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "FragmentDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "EntityFields"
+            },
+            "directives": [],
+            "typeCondition": {
+                "kind": "NamedType",
+                "name": {
+                    "kind": "Name",
+                    "value": "Entity"
+                }
+            },
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "id"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 48,
+        "source": {
+            "body": "\n  fragment EntityFields on Entity {\n    id\n  }\n"
+        }
+    }
+};
+const DIFFERENT_FRAGMENT = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "FragmentDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "EntityFields"
+            },
+            "directives": [],
+            "typeCondition": {
+                "kind": "NamedType",
+                "name": {
+                    "kind": "Name",
+                    "value": "Entity"
+                }
+            },
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "id"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    },
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "name"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 57,
+        "source": {
+            "body": "\n  fragment EntityFields on Entity {\n    id\n    name\n  }\n"
+        }
+    }
+};
+// should compile, both fragments have the same selections
+const QUERY = {
+    "kind": "Document",
+    "definitions": /*#__PURE__*/ unique(/*#__PURE__*/ [
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "testQuery"
+            },
+            "directives": [],
+            "variableDefinitions": [],
+            "operation": "query",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "getEntity"
+                        },
+                        "arguments": [],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "FragmentSpread",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "EntityFields"
+                                    },
+                                    "directives": []
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }
+    ].concat(FRAGMENT.definitions, SAME_FRAGMENT.definitions)),
+    "loc": {
+        "start": 0,
+        "end": 76,
+        "source": {
+            "body": "\n  query testQuery {\n    getEntity {\n      ...EntityFields\n    }\n  }\n\n  \n  \n"
+        }
+    }
+};
+// should not compile, fragments have different selections
+const CONFLICTING_QUERY = gql`
+  query testQuery {
+    getEntity {
+      ...EntityFields
+    }
+  }
+
+  ${FRAGMENT}
+  ${DIFFERENT_FRAGMENT}
+`;
+// should not compile, fragment conflicts with interpolated one
+const CONFLICTING_DEFINITION = gql`
+  query testQuery {
+    getEntity {
+      ...EntityFields
+    }
+  }
+
+  fragment EntityFields on Entity {
+    name
+  }
+
+  ${FRAGMENT}
+`;
+const NESTED_FRAGMENT = {
+    "kind": "Document",
+    "definitions": /*#__PURE__*/ unique(/*#__PURE__*/ [
+        {
+            "kind": "FragmentDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "NestedFields"
+            },
+            "directives": [],
+            "typeCondition": {
+                "kind": "NamedType",
+                "name": {
+                    "kind": "Name",
+                    "value": "Entity"
+                }
+            },
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "nested"
+                        },
+                        "arguments": [],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "FragmentSpread",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "EntityFields"
+                                    },
+                                    "directives": []
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }
+    ].concat(FRAGMENT.definitions)),
+    "loc": {
+        "start": 0,
+        "end": 86,
+        "source": {
+            "body": "\n  fragment NestedFields on Entity {\n    nested {\n      ...EntityFields\n    }\n  }\n\n  \n"
+        }
+    }
+};
+// should not compile, the conflicting fragment is spread through another one
+const CONFLICTING_NESTED_QUERY = gql`
+  query testQuery {
+    getEntity {
+      ...NestedFields
+    }
+  }
+
+  ${NESTED_FRAGMENT}
+  ${DIFFERENT_FRAGMENT}
+`;
@@ -0,0 +1,258 @@
+import { gql } from "@apollo/client";
+const unique = (definitions)=>{
+    const names = {};
+    return definitions.filter((definition)=>{
+        if (definition.kind !== 'FragmentDefinition') {
+            return true;
+        }
+        const name = definition.name.value;
+        if (names[name]) {
+            return false;
+        } else {
+            names[name] = true;
+            return true;
+        }
+    });
+};
+const FRAGMENT = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "FragmentDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "EntityFields"
+            },
+            "directives": [],
+            "typeCondition": {
+                "kind": "NamedType",
+                "name": {
+                    "kind": "Name",
+                    "value": "Entity"
+                }
+            },
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "id"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 35,
+        "source": {
+            "body": "fragment EntityFields on Entity{id}"
+        }
+    }
+};
+const SAME_FRAGMENT = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "FragmentDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "EntityFields"
+            },
+            "directives": [],
+            "typeCondition": {
+                "kind": "NamedType",
+                "name": {
+                    "kind": "Name",
+                    "value": "Entity"
+                }
+            },
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "id"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 35,
+        "source": {
+            "body": "fragment EntityFields on Entity{id}"
+        }
+    }
+};
+const DIFFERENT_FRAGMENT = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "FragmentDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "EntityFields"
+            },
+            "directives": [],
+            "typeCondition": {
+                "kind": "NamedType",
+                "name": {
+                    "kind": "Name",
+                    "value": "Entity"
+                }
+            },
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "id"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    },
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "name"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 40,
+        "source": {
+            "body": "fragment EntityFields on Entity{id name}"
+        }
+    }
+};
+// should compile, both fragments have the same selections
+const QUERY = {
+    "kind": "Document",
+    "definitions": /*#__PURE__*/ unique(/*#__PURE__*/ [
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "testQuery"
+            },
+            "directives": [],
+            "variableDefinitions": [],
+            "operation": "query",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "getEntity"
+                        },
+                        "arguments": [],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "FragmentSpread",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "EntityFields"
+                                    },
+                                    "directives": []
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }
+    ].concat(FRAGMENT.definitions, SAME_FRAGMENT.definitions)),
+    "loc": {
+        "start": 0,
+        "end": 43,
+        "source": {
+            "body": "query testQuery{getEntity{...EntityFields}}"
+        }
+    }
+};
+// should not compile, fragments have different selections
+const CONFLICTING_QUERY = gql`query testQuery{getEntity{...EntityFields}}${FRAGMENT} ${DIFFERENT_FRAGMENT}`;
+// should not compile, fragment conflicts with interpolated one
+const CONFLICTING_DEFINITION = gql`query testQuery{getEntity{...EntityFields}}fragment EntityFields on Entity{name}${FRAGMENT}`;
+const NESTED_FRAGMENT = {
+    "kind": "Document",
+    "definitions": /*#__PURE__*/ unique(/*#__PURE__*/ [
+        {
+            "kind": "FragmentDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "NestedFields"
+            },
+            "directives": [],
+            "typeCondition": {
+                "kind": "NamedType",
+                "name": {
+                    "kind": "Name",
+                    "value": "Entity"
+                }
+            },
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "nested"
+                        },
+                        "arguments": [],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "FragmentSpread",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "EntityFields"
+                                    },
+                                    "directives": []
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }
+    ].concat(FRAGMENT.definitions)),
+    "loc": {
+        "start": 0,
+        "end": 56,
+        "source": {
+            "body": "fragment NestedFields on Entity{nested{...EntityFields}}"
+        }
+    }
+};
+// should not compile, the conflicting fragment is spread through another one
+const CONFLICTING_NESTED_QUERY = gql`query testQuery{getEntity{...NestedFields}}${NESTED_FRAGMENT} ${DIFFERENT_FRAGMENT}`;
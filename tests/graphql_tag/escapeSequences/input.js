@@ -0,0 +1,7 @@
+import { gql } from "@apollo/client";
+
+const GET_GREETING = gql`
+  query GetGreeting {
+    \u0067reeting(text: "tab\\tseparated", note: "line\\nbreak")
+  }
+`;
@@ -0,0 +1,60 @@
+import { gql } from "@apollo/client";
+const GET_GREETING = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "GetGreeting"
+            },
+            "directives": [],
+            "variableDefinitions": [],
+            "operation": "query",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "greeting"
+                        },
+                        "arguments": [
+                            {
+                                "kind": "Argument",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "text"
+                                },
+                                "value": {
+                                    "kind": "StringValue",
+                                    "value": "tab	separated"
+                                }
+                            },
+                            {
+                                "kind": "Argument",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "note"
+                                },
+                                "value": {
+                                    "kind": "StringValue",
+                                    "value": "line\nbreak"
+                                }
+                            }
+                        ],
+                        "directives": []
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 69,
+        "source": {
+            "body": 'query GetGreeting{greeting(text:"tab\\tseparated",note:"line\\nbreak")}'
+        }
+    }
+};
@@ -0,0 +1,21 @@
+import { gql } from "@apollo/client";
+
+/* @export */
+export const USER_DOCUMENT = gql`
+  query GetUser($id: ID!) {
+    user(id: $id) {
+      ...UserFields
+    }
+  }
+
+  mutation RenameUser($id: ID!, $name: String!) {
+    renameUser(id: $id, name: $name) {
+      id
+    }
+  }
+
+  fragment UserFields on User {
+    id
+    name
+  }
+`;
@@ -0,0 +1,32 @@
+import { gql } from "@apollo/client";
+
+const FRAGMENT = gql`
+  fragment EntityFields on Entity {
+    id
+  }
+`;
+
+// should warn, "UnknownFields" is never defined
+const QUERY_WITH_UNKNOWN_SPREAD = gql`
+  query testQuery {
+    getEntity {
+      ...EntityFields
+      ...UnknownFields
+    }
+  }
+
+  ${FRAGMENT}
+`;
+
+// should warn, "UnusedFields" is never spread
+const QUERY_WITH_UNUSED_FRAGMENT = gql`
+  query testQuery {
+    getEntity {
+      id
+    }
+  }
+
+  fragment UnusedFields on Entity {
+    name
+  }
+`;
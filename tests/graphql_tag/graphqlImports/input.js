@@ -0,0 +1,23 @@
+import { gql } from "@apollo/client";
+
+// should inline fragments of imported files
+const QUERY = gql`
+  #import "./fragments/UserFields.graphql"
+
+  query testQuery {
+    getUser {
+      ...UserFields
+    }
+  }
+`;
+
+// should not compile, imported file does not exist
+const MISSING_IMPORT = gql`
+  #import "./fragments/MissingFields.graphql"
+
+  query testQuery {
+    getUser {
+      ...MissingFields
+    }
+  }
+`;
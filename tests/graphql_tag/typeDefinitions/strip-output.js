@@ -0,0 +1,3 @@
+import { gql } from "graphql-tag";
+// type system definitions aren't executable, they get reported
+const TYPES = gql`type User{id:ID!}query GetUser{user{id}}`;
@@ -0,0 +1,37 @@
+import { gql } from "@apollo/client";
+
+const TYPE = "User";
+const FIELDS = "id name";
+
+const FRAGMENT = gql`
+  fragment ${TYPE}Fields on ${TYPE} {
+    ${FIELDS}
+    avatar(size: ${64})
+  }
+`;
+
+const SEARCH = gql`
+  query Search {
+    search(text: "${TYPE} list") {
+      ${FIELDS}
+    }
+  }
+  ${FRAGMENT}
+`;
+
+const COMMENTED = gql`
+  # fields of ${TYPE}
+  query Commented {
+    user {
+      ${FIELDS}
+    }
+  }
+`;
+
+const ESCAPED = gql`
+  query Escaped {
+    search(text: "\\${TYPE}") {
+      ${FIELDS}
+    }
+  }
+`;
@@ -0,0 +1,30 @@
+import { gql } from "@apollo/client";
+
+const ADDRESS_FIELDS = gql`
+  fragment AddressFields on User {
+    address
+  }
+`;
+
+const USER_FIELDS = gql`
+  fragment UserFields on User {
+    id
+    name
+    ...AddressFields @include(if: $withAddress)
+  }
+
+  ${ADDRESS_FIELDS}
+`;
+
+const GET_USER = gql`
+  query GetUser($withAddress: Boolean!) {
+    user {
+      ...UserFields
+      friends {
+        ...UserFields @skip(if: $withAddress)
+      }
+    }
+  }
+
+  ${USER_FIELDS}
+`;
@@ -0,0 +1,420 @@
+import { gql } from "@apollo/client";
+const ADDRESS_FIELDS = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "FragmentDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "AddressFields"
+            },
+            "directives": [],
+            "typeCondition": {
+                "kind": "NamedType",
+                "name": {
+                    "kind": "Name",
+                    "value": "User"
+                }
+            },
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "address"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 39,
+        "source": {
+            "body": "fragment AddressFields on User{address}"
+        }
+    }
+};
+const USER_FIELDS = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "FragmentDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "UserFields"
+            },
+            "directives": [],
+            "typeCondition": {
+                "kind": "NamedType",
+                "name": {
+                    "kind": "Name",
+                    "value": "User"
+                }
+            },
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "id"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    },
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "name"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    },
+                    {
+                        "kind": "FragmentSpread",
+                        "name": {
+                            "kind": "Name",
+                            "value": "AddressFields"
+                        },
+                        "directives": [
+                            {
+                                "kind": "Directive",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "include"
+                                },
+                                "arguments": [
+                                    {
+                                        "kind": "Argument",
+                                        "name": {
+                                            "kind": "Name",
+                                            "value": "if"
+                                        },
+                                        "value": {
+                                            "kind": "Variable",
+                                            "name": {
+                                                "kind": "Name",
+                                                "value": "withAddress"
+                                            }
+                                        }
+                                    }
+                                ]
+                            }
+                        ]
+                    }
+                ]
+            }
+        },
+        {
+            "kind": "FragmentDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "AddressFields"
+            },
+            "directives": [],
+            "typeCondition": {
+                "kind": "NamedType",
+                "name": {
+                    "kind": "Name",
+                    "value": "User"
+                }
+            },
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "address"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 116,
+        "source": {
+            "body": "fragment UserFields on User{id name...AddressFields@include(if:$withAddress)}fragment AddressFields on User{address}"
+        }
+    }
+};
+const GET_USER = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "GetUser"
+            },
+            "directives": [],
+            "variableDefinitions": [
+                {
+                    "kind": "VariableDefinition",
+                    "directives": [],
+                    "variable": {
+                        "kind": "Variable",
+                        "name": {
+                            "kind": "Name",
+                            "value": "withAddress"
+                        }
+                    },
+                    "type": {
+                        "kind": "NonNullType",
+                        "type": {
+                            "kind": "NamedType",
+                            "name": {
+                                "kind": "Name",
+                                "value": "Boolean"
+                            }
+                        }
+                    }
+                }
+            ],
+            "operation": "query",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "user"
+                        },
+                        "arguments": [],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "InlineFragment",
+                                    "directives": [],
+                                    "typeCondition": {
+                                        "kind": "NamedType",
+                                        "name": {
+                                            "kind": "Name",
+                                            "value": "User"
+                                        }
+                                    },
+                                    "selectionSet": {
+                                        "kind": "SelectionSet",
+                                        "selections": [
+                                            {
+                                                "kind": "Field",
+                                                "name": {
+                                                    "kind": "Name",
+                                                    "value": "id"
+                                                },
+                                                "arguments": [],
+                                                "directives": []
+                                            },
+                                            {
+                                                "kind": "Field",
+                                                "name": {
+                                                    "kind": "Name",
+                                                    "value": "name"
+                                                },
+                                                "arguments": [],
+                                                "directives": []
+                                            },
+                                            {
+                                                "kind": "InlineFragment",
+                                                "directives": [
+                                                    {
+                                                        "kind": "Directive",
+                                                        "name": {
+                                                            "kind": "Name",
+                                                            "value": "include"
+                                                        },
+                                                        "arguments": [
+                                                            {
+                                                                "kind": "Argument",
+                                                                "name": {
+                                                                    "kind": "Name",
+                                                                    "value": "if"
+                                                                },
+                                                                "value": {
+                                                                    "kind": "Variable",
+                                                                    "name": {
+                                                                        "kind": "Name",
+                                                                        "value": "withAddress"
+                                                                    }
+                                                                }
+                                                            }
+                                                        ]
+                                                    }
+                                                ],
+                                                "typeCondition": {
+                                                    "kind": "NamedType",
+                                                    "name": {
+                                                        "kind": "Name",
+                                                        "value": "User"
+                                                    }
+                                                },
+                                                "selectionSet": {
+                                                    "kind": "SelectionSet",
+                                                    "selections": [
+                                                        {
+                                                            "kind": "Field",
+                                                            "name": {
+                                                                "kind": "Name",
+                                                                "value": "address"
+                                                            },
+                                                            "arguments": [],
+                                                            "directives": []
+                                                        }
+                                                    ]
+                                                }
+                                            }
+                                        ]
+                                    }
+                                },
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "friends"
+                                    },
+                                    "arguments": [],
+                                    "directives": [],
+                                    "selectionSet": {
+                                        "kind": "SelectionSet",
+                                        "selections": [
+                                            {
+                                                "kind": "InlineFragment",
+                                                "directives": [
+                                                    {
+                                                        "kind": "Directive",
+                                                        "name": {
+                                                            "kind": "Name",
+                                                            "value": "skip"
+                                                        },
+                                                        "arguments": [
+                                                            {
+                                                                "kind": "Argument",
+                                                                "name": {
+                                                                    "kind": "Name",
+                                                                    "value": "if"
+                                                                },
+                                                                "value": {
+                                                                    "kind": "Variable",
+                                                                    "name": {
+                                                                        "kind": "Name",
+                                                                        "value": "withAddress"
+                                                                    }
+                                                                }
+                                                            }
+                                                        ]
+                                                    }
+                                                ],
+                                                "typeCondition": {
+                                                    "kind": "NamedType",
+                                                    "name": {
+                                                        "kind": "Name",
+                                                        "value": "User"
+                                                    }
+                                                },
+                                                "selectionSet": {
+                                                    "kind": "SelectionSet",
+                                                    "selections": [
+                                                        {
+                                                            "kind": "Field",
+                                                            "name": {
+                                                                "kind": "Name",
+                                                                "value": "id"
+                                                            },
+                                                            "arguments": [],
+                                                            "directives": []
+                                                        },
+                                                        {
+                                                            "kind": "Field",
+                                                            "name": {
+                                                                "kind": "Name",
+                                                                "value": "name"
+                                                            },
+                                                            "arguments": [],
+                                                            "directives": []
+                                                        },
+                                                        {
+                                                            "kind": "InlineFragment",
+                                                            "directives": [
+                                                                {
+                                                                    "kind": "Directive",
+                                                                    "name": {
+                                                                        "kind": "Name",
+                                                                        "value": "include"
+                                                                    },
+                                                                    "arguments": [
+                                                                        {
+                                                                            "kind": "Argument",
+                                                                            "name": {
+                                                                                "kind": "Name",
+                                                                                "value": "if"
+                                                                            },
+                                                                            "value": {
+                                                                                "kind": "Variable",
+                                                                                "name": {
+                                                                                    "kind": "Name",
+                                                                                    "value": "withAddress"
+                                                                                }
+                                                                            }
+                                                                        }
+                                                                    ]
+                                                                }
+                                                            ],
+                                                            "typeCondition": {
+                                                                "kind": "NamedType",
+                                                                "name": {
+                                                                    "kind": "Name",
+                                                                    "value": "User"
+                                                                }
+                                                            },
+                                                            "selectionSet": {
+                                                                "kind": "SelectionSet",
+                                                                "selections": [
+                                                                    {
+                                                                        "kind": "Field",
+                                                                        "name": {
+                                                                            "kind": "Name",
+                                                                            "value": "address"
+                                                                        },
+                                                                        "arguments": [],
+                                                                        "directives": []
+                                                                    }
+                                                                ]
+                                                            }
+                                                        }
+                                                    ]
+                                                }
+                                            }
+                                        ]
+                                    }
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 201,
+        "source": {
+            "body": "query GetUser($withAddress:Boolean!){user{...on User{id name...on User@include(if:$withAddress){address}}friends{...on User@skip(if:$withAddress){id name...on User@include(if:$withAddress){address}}}}}"
+        }
+    }
+};
@@ -0,0 +1,31 @@
+import { gql } from "@apollo/client";
+
+const GET_USER = gql`
+  query GetUser($withFriends: Boolean!) {
+    user {
+      id
+      friends @include(if: $withFriends) @connection(key: "friends") {
+        id
+      }
+    }
+  }
+`;
+
+const GET_TEAM = gql`
+  query GetTeam($skipMembers: Boolean!) {
+    team {
+      id
+      members @skipp(if: $skipMembers) {
+        id
+      }
+    }
+  }
+`;
+
+const GET_FEED = gql`
+  query GetFeed {
+    feed @live {
+      id
+    }
+  }
+`;
@@ -0,0 +1,157 @@
+import { gql } from "@apollo/client";
+const GET_USER = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "GetUser"
+            },
+            "directives": [],
+            "variableDefinitions": [
+                {
+                    "kind": "VariableDefinition",
+                    "directives": [],
+                    "variable": {
+                        "kind": "Variable",
+                        "name": {
+                            "kind": "Name",
+                            "value": "withFriends"
+                        }
+                    },
+                    "type": {
+                        "kind": "NonNullType",
+                        "type": {
+                            "kind": "NamedType",
+                            "name": {
+                                "kind": "Name",
+                                "value": "Boolean"
+                            }
+                        }
+                    }
+                }
+            ],
+            "operation": "query",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "user"
+                        },
+                        "arguments": [],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "id"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                },
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "friends"
+                                    },
+                                    "arguments": [],
+                                    "directives": [
+                                        {
+                                            "kind": "Directive",
+                                            "name": {
+                                                "kind": "Name",
+                                                "value": "include"
+                                            },
+                                            "arguments": [
+                                                {
+                                                    "kind": "Argument",
+                                                    "name": {
+                                                        "kind": "Name",
+                                                        "value": "if"
+                                                    },
+                                                    "value": {
+                                                        "kind": "Variable",
+                                                        "name": {
+                                                            "kind": "Name",
+                                                            "value": "withFriends"
+                                                        }
+                                                    }
+                                                }
+                                            ]
+                                        },
+                                        {
+                                            "kind": "Directive",
+                                            "name": {
+                                                "kind": "Name",
+                                                "value": "connection"
+                                            },
+                                            "arguments": [
+                                                {
+                                                    "kind": "Argument",
+                                                    "name": {
+                                                        "kind": "Name",
+                                                        "value": "key"
+                                                    },
+                                                    "value": {
+                                                        "kind": "StringValue",
+                                                        "value": "friends"
+                                                    }
+                                                }
+                                            ]
+                                        }
+                                    ],
+                                    "selectionSet": {
+                                        "kind": "SelectionSet",
+                                        "selections": [
+                                            {
+                                                "kind": "Field",
+                                                "name": {
+                                                    "kind": "Name",
+                                                    "value": "id"
+                                                },
+                                                "arguments": [],
+                                                "directives": []
+                                            }
+                                        ]
+                                    }
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 163,
+        "source": {
+            "body": '\n  query GetUser($withFriends: Boolean!) {\n    user {\n      id\n      friends @include(if: $withFriends) @connection(key: "friends") {\n        id\n      }\n    }\n  }\n'
+        }
+    }
+};
+const GET_TEAM = gql`
+  query GetTeam($skipMembers: Boolean!) {
+    team {
+      id
+      members @skipp(if: $skipMembers) {
+        id
+      }
+    }
+  }
+`;
+const GET_FEED = gql`
+  query GetFeed {
+    feed @live {
+      id
+    }
+  }
+`;
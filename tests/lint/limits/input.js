@@ -0,0 +1,49 @@
+import { gql } from "@apollo/client";
+
+const GET_USER = gql`
+  query GetUser {
+    user {
+      id
+      friends {
+        id
+      }
+    }
+  }
+`;
+
+const GET_FRIENDS = gql`
+  query GetFriends {
+    user {
+      friends {
+        ...UserFriends
+      }
+    }
+  }
+
+  fragment UserFriends on User {
+    friends {
+      id
+    }
+  }
+`;
+
+const GET_USERS = gql`
+  query GetUsers {
+    first: user(id: 1) {
+      id
+    }
+    second: user(id: 2) {
+      id
+    }
+  }
+`;
+
+const SEARCH = gql`
+  query Search {
+    search(term: "user") {
+      id
+      name
+      email
+    }
+  }
+`;
@@ -0,0 +1,108 @@
+import { gql } from "@apollo/client";
+const GET_USER = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "GetUser"
+            },
+            "directives": [],
+            "variableDefinitions": [],
+            "operation": "query",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "user"
+                        },
+                        "arguments": [],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "id"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                },
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "friends"
+                                    },
+                                    "arguments": [],
+                                    "directives": [],
+                                    "selectionSet": {
+                                        "kind": "SelectionSet",
+                                        "selections": [
+                                            {
+                                                "kind": "Field",
+                                                "name": {
+                                                    "kind": "Name",
+                                                    "value": "id"
+                                                },
+                                                "arguments": [],
+                                                "directives": []
+                                            }
+                                        ]
+                                    }
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 84,
+        "source": {
+            "body": "\n  query GetUser {\n    user {\n      id\n      friends {\n        id\n      }\n    }\n  }\n"
+        }
+    }
+};
+const GET_FRIENDS = gql`
+  query GetFriends {
+    user {
+      friends {
+        ...UserFriends
+      }
+    }
+  }
+
+  fragment UserFriends on User {
+    friends {
+      id
+    }
+  }
+`;
+const GET_USERS = gql`
+  query GetUsers {
+    first: user(id: 1) {
+      id
+    }
+    second: user(id: 2) {
+      id
+    }
+  }
+`;
+const SEARCH = gql`
+  query Search {
+    search(term: "user") {
+      id
+      name
+      email
+    }
+  }
+`;
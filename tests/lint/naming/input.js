@@ -0,0 +1,43 @@
+import { gql } from "@apollo/client";
+
+// should compile
+const VALID = gql`
+  query GetEntity {
+    getEntity
+  }
+`;
+
+// should not compile, operation must have name
+const ANONYMOUS = gql`
+  {
+    getEntity
+  }
+`;
+
+// should not compile, name is not PascalCase
+const LOWERCASE = gql`
+  query getEntity {
+    getEntity
+  }
+`;
+
+// should not compile, name is already used
+const DUPLICATE = gql`
+  query GetEntity {
+    getEntity
+  }
+`;
+
+// should compile
+const FRAGMENT = gql`
+  fragment EntityFields on Entity {
+    id
+  }
+`;
+
+// should not compile, fragment name does not start with type condition
+const MISMATCHED_FRAGMENT = gql`
+  fragment Fields on Entity {
+    id
+  }
+`;
@@ -0,0 +1,104 @@
+import { gql } from "@apollo/client";
+// should compile
+const VALID = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "GetEntity"
+            },
+            "directives": [],
+            "variableDefinitions": [],
+            "operation": "query",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "getEntity"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 39,
+        "source": {
+            "body": "\n  query GetEntity {\n    getEntity\n  }\n"
+        }
+    }
+};
+// should not compile, operation must have name
+const ANONYMOUS = gql`
+  {
+    getEntity
+  }
+`;
+// should not compile, name is not PascalCase
+const LOWERCASE = gql`
+  query getEntity {
+    getEntity
+  }
+`;
+// should not compile, name is already used
+const DUPLICATE = gql`
+  query GetEntity {
+    getEntity
+  }
+`;
+// should compile
+const FRAGMENT = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "FragmentDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "EntityFields"
+            },
+            "directives": [],
+            "typeCondition": {
+                "kind": "NamedType",
+                "name": {
+                    "kind": "Name",
+                    "value": "Entity"
+                }
+            },
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "id"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 48,
+        "source": {
+            "body": "\n  fragment EntityFields on Entity {\n    id\n  }\n"
+        }
+    }
+};
+// should not compile, fragment name does not start with type condition
+const MISMATCHED_FRAGMENT = gql`
+  fragment Fields on Entity {
+    id
+  }
+`;
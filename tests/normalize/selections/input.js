@@ -0,0 +1,35 @@
+import { gql } from "@apollo/client";
+
+const GET_USER = gql`
+  query GetUser($id: ID!) {
+    user(id: $id) {
+      name
+      id
+      friends(first: 10) {
+        name
+      }
+      ... {
+        id
+        email
+      }
+      friends(first:10) {
+        id
+      }
+      ... on User @include(if: true) {
+        address
+      }
+    }
+  }
+`;
+
+const USER_FIELDS = gql`
+  fragment UserFields on User {
+    name
+    ... on User {
+      id
+    }
+    ... on Admin {
+      role
+    }
+  }
+`;
@@ -0,0 +1,271 @@
+import { gql } from "@apollo/client";
+const GET_USER = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "GetUser"
+            },
+            "directives": [],
+            "variableDefinitions": [
+                {
+                    "kind": "VariableDefinition",
+                    "directives": [],
+                    "variable": {
+                        "kind": "Variable",
+                        "name": {
+                            "kind": "Name",
+                            "value": "id"
+                        }
+                    },
+                    "type": {
+                        "kind": "NonNullType",
+                        "type": {
+                            "kind": "NamedType",
+                            "name": {
+                                "kind": "Name",
+                                "value": "ID"
+                            }
+                        }
+                    }
+                }
+            ],
+            "operation": "query",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "user"
+                        },
+                        "arguments": [
+                            {
+                                "kind": "Argument",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "id"
+                                },
+                                "value": {
+                                    "kind": "Variable",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "id"
+                                    }
+                                }
+                            }
+                        ],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "email"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                },
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "friends"
+                                    },
+                                    "arguments": [
+                                        {
+                                            "kind": "Argument",
+                                            "name": {
+                                                "kind": "Name",
+                                                "value": "first"
+                                            },
+                                            "value": {
+                                                "kind": "IntValue",
+                                                "value": "10"
+                                            }
+                                        }
+                                    ],
+                                    "directives": [],
+                                    "selectionSet": {
+                                        "kind": "SelectionSet",
+                                        "selections": [
+                                            {
+                                                "kind": "Field",
+                                                "name": {
+                                                    "kind": "Name",
+                                                    "value": "id"
+                                                },
+                                                "arguments": [],
+                                                "directives": []
+                                            },
+                                            {
+                                                "kind": "Field",
+                                                "name": {
+                                                    "kind": "Name",
+                                                    "value": "name"
+                                                },
+                                                "arguments": [],
+                                                "directives": []
+                                            }
+                                        ]
+                                    }
+                                },
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "id"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                },
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "name"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                },
+                                {
+                                    "kind": "InlineFragment",
+                                    "directives": [
+                                        {
+                                            "kind": "Directive",
+                                            "name": {
+                                                "kind": "Name",
+                                                "value": "include"
+                                            },
+                                            "arguments": [
+                                                {
+                                                    "kind": "Argument",
+                                                    "name": {
+                                                        "kind": "Name",
+                                                        "value": "if"
+                                                    },
+                                                    "value": {
+                                                        "kind": "BooleanValue",
+                                                        "value": true
+                                                    }
+                                                }
+                                            ]
+                                        }
+                                    ],
+                                    "typeCondition": {
+                                        "kind": "NamedType",
+                                        "name": {
+                                            "kind": "Name",
+                                            "value": "User"
+                                        }
+                                    },
+                                    "selectionSet": {
+                                        "kind": "SelectionSet",
+                                        "selections": [
+                                            {
+                                                "kind": "Field",
+                                                "name": {
+                                                    "kind": "Name",
+                                                    "value": "address"
+                                                },
+                                                "arguments": [],
+                                                "directives": []
+                                            }
+                                        ]
+                                    }
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 183,
+        "source": {
+            "body": "query GetUser($id: ID!) {\n  user(id: $id) {\n    email\n    friends(first: 10) {\n      id\n      name\n    }\n    id\n    name\n    ... on User @include(if: true) {\n      address\n    }\n  }\n}"
+        }
+    }
+};
+const USER_FIELDS = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "FragmentDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "UserFields"
+            },
+            "directives": [],
+            "typeCondition": {
+                "kind": "NamedType",
+                "name": {
+                    "kind": "Name",
+                    "value": "User"
+                }
+            },
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "id"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    },
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "name"
+                        },
+                        "arguments": [],
+                        "directives": []
+                    },
+                    {
+                        "kind": "InlineFragment",
+                        "directives": [],
+                        "typeCondition": {
+                            "kind": "NamedType",
+                            "name": {
+                                "kind": "Name",
+                                "value": "Admin"
+                            }
+                        },
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "role"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 73,
+        "source": {
+            "body": "fragment UserFields on User {\n  id\n  name\n  ... on Admin {\n    role\n  }\n}"
+        }
+    }
+};
@@ -0,0 +1,22 @@
+import { gql } from "@apollo/client";
+
+const SEARCH = gql`
+  query Search {
+    search(term: "say \\"hi\\" now", path: "C:\\\\Users", accent: "\\u00e9") {
+      id
+    }
+    translate(text: "ééééééééééééééééééééééééééééééé", locale: "fr", format: PLAIN) {
+      text
+    }
+    describe(text: """a "quoted" block""") {
+      id
+    }
+    document(text: """
+      first line
+        indented line
+      \\"""
+    """) {
+      id
+    }
+  }
+`;
@@ -0,0 +1,214 @@
+import { gql } from "@apollo/client";
+const SEARCH = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "Search"
+            },
+            "directives": [],
+            "variableDefinitions": [],
+            "operation": "query",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "describe"
+                        },
+                        "arguments": [
+                            {
+                                "kind": "Argument",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "text"
+                                },
+                                "value": {
+                                    "kind": "StringValue",
+                                    "value": 'a "quoted" block',
+                                    "block": true
+                                }
+                            }
+                        ],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "id"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                }
+                            ]
+                        }
+                    },
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "document"
+                        },
+                        "arguments": [
+                            {
+                                "kind": "Argument",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "text"
+                                },
+                                "value": {
+                                    "kind": "StringValue",
+                                    "value": 'first line\n  indented line\n"""',
+                                    "block": true
+                                }
+                            }
+                        ],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "id"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                }
+                            ]
+                        }
+                    },
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "search"
+                        },
+                        "arguments": [
+                            {
+                                "kind": "Argument",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "term"
+                                },
+                                "value": {
+                                    "kind": "StringValue",
+                                    "value": 'say "hi" now'
+                                }
+                            },
+                            {
+                                "kind": "Argument",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "path"
+                                },
+                                "value": {
+                                    "kind": "StringValue",
+                                    "value": "C:\\Users"
+                                }
+                            },
+                            {
+                                "kind": "Argument",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "accent"
+                                },
+                                "value": {
+                                    "kind": "StringValue",
+                                    "value": "é"
+                                }
+                            }
+                        ],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "id"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                }
+                            ]
+                        }
+                    },
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "translate"
+                        },
+                        "arguments": [
+                            {
+                                "kind": "Argument",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "text"
+                                },
+                                "value": {
+                                    "kind": "StringValue",
+                                    "value": "ééééééééééééééééééééééééééééééé"
+                                }
+                            },
+                            {
+                                "kind": "Argument",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "locale"
+                                },
+                                "value": {
+                                    "kind": "StringValue",
+                                    "value": "fr"
+                                }
+                            },
+                            {
+                                "kind": "Argument",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "format"
+                                },
+                                "value": {
+                                    "kind": "EnumValue",
+                                    "value": "PLAIN"
+                                }
+                            }
+                        ],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "text"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 356,
+        "source": {
+            "body": 'query Search {\n  describe(text: """a "quoted" block""") {\n    id\n  }\n  document(text: """\n  first line\n    indented line\n  \\"""\n  """) {\n    id\n  }\n  search(term: "say \\"hi\\" now", path: "C:\\\\Users", accent: "é") {\n    id\n  }\n  translate(text: "ééééééééééééééééééééééééééééééé", locale: "fr", format: PLAIN) {\n    text\n  }\n}'
+        }
+    }
+};
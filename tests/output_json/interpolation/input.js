@@ -0,0 +1,18 @@
+import { gql } from "@apollo/client";
+
+const FRAGMENT = gql`
+  fragment EntityFields on Entity {
+    id
+    name
+  }
+`;
+
+const QUERY = gql`
+  query testQuery {
+    entity {
+      ...EntityFields
+    }
+  }
+
+  ${FRAGMENT}
+`;
@@ -0,0 +1,28 @@
+import { gql } from "@apollo/client";
+const unique = (definitions)=>{
+    const names = {};
+    return definitions.filter((definition)=>{
+        if (definition.kind !== 'FragmentDefinition') {
+            return true;
+        }
+        const name = definition.name.value;
+        if (names[name]) {
+            return false;
+        } else {
+            names[name] = true;
+            return true;
+        }
+    });
+};
+const FRAGMENT = /*#__PURE__*/ JSON.parse('{"kind":"Document","definitions":[{"kind":"FragmentDefinition","name":{"kind":"Name","value":"EntityFields"},"directives":[],"typeCondition":{"kind":"NamedType","name":{"kind":"Name","value":"Entity"}},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"},"arguments":[],"directives":[]},{"kind":"Field","name":{"kind":"Name","value":"name"},"arguments":[],"directives":[]}]}}],"loc":{"start":0,"end":57,"source":{"body":"\\n  fragment EntityFields on Entity {\\n    id\\n    name\\n  }\\n"}}}');
+const QUERY = {
+    "kind": "Document",
+    "definitions": /*#__PURE__*/ unique(/*#__PURE__*/ JSON.parse('[{"kind":"OperationDefinition","name":{"kind":"Name","value":"testQuery"},"directives":[],"variableDefinitions":[],"operation":"query","selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"entity"},"arguments":[],"directives":[],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"FragmentSpread","name":{"kind":"Name","value":"EntityFields"},"directives":[]}]}}]}}]').concat(FRAGMENT.definitions)),
+    "loc": {
+        "start": 0,
+        "end": 70,
+        "source": {
+            "body": "\n  query testQuery {\n    entity {\n      ...EntityFields\n    }\n  }\n\n  \n"
+        }
+    }
+};
@@ -0,0 +1,10 @@
+import { gql } from "@apollo/client";
+
+const QUERY = gql`
+  query testQuery($id: ID!) {
+    entity(id: $id) {
+      id
+      name
+    }
+  }
+`;
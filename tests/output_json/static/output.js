@@ -0,0 +1,2 @@
+import { gql } from "@apollo/client";
+const QUERY = /*#__PURE__*/ JSON.parse('{"kind":"Document","definitions":[{"kind":"OperationDefinition","name":{"kind":"Name","value":"testQuery"},"directives":[],"variableDefinitions":[{"kind":"VariableDefinition","directives":[],"variable":{"kind":"Variable","name":{"kind":"Name","value":"id"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"ID"}}}}],"operation":"query","selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"entity"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"id"},"value":{"kind":"Variable","name":{"kind":"Name","value":"id"}}}],"directives":[],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"},"arguments":[],"directives":[]},{"kind":"Field","name":{"kind":"Name","value":"name"},"arguments":[],"directives":[]}]}}]}}],"loc":{"start":0,"end":83,"source":{"body":"\\n  query testQuery($id: ID!) {\\n    entity(id: $id) {\\n      id\\n      name\\n    }\\n  }\\n"}}}');
@@ -0,0 +1,13 @@
+import request, { gql } from "graphql-request";
+const GET_USER = "query GetUser($id:ID!){user(id:$id){id}}";
+// the default export is not a gql tag
+const GET_USERS = request`
+  query GetUsers {
+    users {
+      id
+    }
+  }
+`;
+request("/graphql", GET_USER, {
+    id: "1"
+});
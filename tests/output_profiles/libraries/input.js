@@ -0,0 +1,40 @@
+import { gql } from "@apollo/client";
+import { gql as requestGql } from "graphql-request";
+
+const USER_FIELDS = requestGql`
+  fragment UserFields on User {
+    id
+    name
+  }
+`;
+
+const GET_USER = requestGql`
+  # fetched with graphql-request
+  query GetUser {
+    user {
+      ...UserFields
+    }
+  }
+  ${USER_FIELDS}
+`;
+
+const GET_VIEWER = requestGql`
+  query GetViewer {
+    viewer {
+      id
+    }
+  }
+`;
+
+const GET_FRIENDS = gql`
+  query GetFriends {
+    friends {
+      id
+    }
+  }
+`;
+
+// should not compile
+const INVALID = requestGql`
+  query Invalid {
+`;
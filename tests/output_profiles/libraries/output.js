@@ -0,0 +1,59 @@
+import { gql } from "@apollo/client";
+import { gql as requestGql } from "graphql-request";
+const USER_FIELDS = "fragment UserFields on User{id name}";
+const GET_USER = `query GetUser{user{...UserFields}}${USER_FIELDS}`;
+const GET_VIEWER = "query GetViewer{viewer{id}}";
+const GET_FRIENDS = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "GetFriends"
+            },
+            "directives": [],
+            "variableDefinitions": [],
+            "operation": "query",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "friends"
+                        },
+                        "arguments": [],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "id"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 55,
+        "source": {
+            "body": "\n  query GetFriends {\n    friends {\n      id\n    }\n  }\n"
+        }
+    }
+};
+// should not compile
+const INVALID = requestGql`
+  query Invalid {
+`;
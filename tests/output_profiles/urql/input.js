@@ -0,0 +1,35 @@
+import { gql } from "urql";
+
+const USER_FIELDS = gql`
+  fragment UserFields on User {
+    id
+    name
+  }
+`;
+
+const GET_USER = gql`
+  query GetUser($id: ID!) {
+    user(id: $id) {
+      id
+      bio(locale: "日本語")
+    }
+  }
+`;
+
+const GET_VIEWER = gql`
+  {
+    viewer {
+      id
+    }
+  }
+`;
+
+// keyed at runtime, its definitions are only known then
+const GET_FRIENDS = gql`
+  query GetFriends {
+    friends {
+      ...UserFields
+    }
+  }
+  ${USER_FIELDS}
+`;
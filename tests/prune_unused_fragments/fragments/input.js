@@ -0,0 +1,35 @@
+import { gql } from "@apollo/client";
+
+const USER_FIELDS = gql`
+  fragment UserFields on User {
+    id
+    name
+    ...AddressFields
+  }
+
+  fragment AddressFields on User {
+    address
+  }
+`;
+
+const TEAM_FIELDS = gql`
+  fragment TeamFields on Team {
+    id
+    members
+  }
+`;
+
+const GET_USER = gql`
+  query GetUser {
+    user {
+      ...UserFields
+    }
+  }
+
+  fragment UnusedFields on User {
+    id
+  }
+
+  ${USER_FIELDS}
+  ${TEAM_FIELDS}
+`;
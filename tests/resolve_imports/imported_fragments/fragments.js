@@ -0,0 +1,21 @@
+import { gql } from "@apollo/client";
+import { AVATAR_FIELDS } from "./user";
+
+export const ENTITY_FIELDS = gql`
+  fragment EntityFields on Entity {
+    id
+    name
+  }
+`;
+
+const USER_FIELDS = gql`
+  fragment UserFields on User {
+    id
+    ...AvatarFields
+  }
+
+  ${AVATAR_FIELDS}
+`;
+
+export { USER_FIELDS };
+export default ENTITY_FIELDS;
@@ -0,0 +1,41 @@
+import { gql } from "@apollo/client";
+import DEFAULT_FIELDS, { ENTITY_FIELDS, USER_FIELDS as FIELDS } from "./fragments";
+import { UNKNOWN_FIELDS } from "./missing";
+
+// should inline fragments from both modules
+const QUERY = gql`
+  query testQuery {
+    getEntity {
+      ...EntityFields
+    }
+    getUser {
+      ...UserFields
+    }
+  }
+
+  ${ENTITY_FIELDS}
+  ${FIELDS}
+`;
+
+// should inline the default export once
+const DEFAULT_QUERY = gql`
+  query testQuery {
+    getEntity {
+      ...EntityFields
+    }
+  }
+
+  ${DEFAULT_FIELDS}
+  ${ENTITY_FIELDS}
+`;
+
+// should concatenate at runtime, module can not be resolved
+const UNRESOLVED_QUERY = gql`
+  query testQuery {
+    getEntity {
+      ...UnknownFields
+    }
+  }
+
+  ${UNKNOWN_FIELDS}
+`;
@@ -0,0 +1,7 @@
+import { gql } from "@apollo/client";
+
+export const AVATAR_FIELDS = gql`
+  fragment AvatarFields on User {
+    avatar
+  }
+`;
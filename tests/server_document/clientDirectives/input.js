@@ -0,0 +1,42 @@
+import { gql } from "@apollo/client";
+
+const CART_FIELDS = gql`
+  fragment CartFields on Cart {
+    isOpen @client
+  }
+`;
+
+const GET_USER = gql`
+  query GetUser($id: ID!) {
+    currentUserId @client @export(as: "id")
+    user(id: $id) {
+      id
+      name
+      isSelected @client
+      friends @connection(key: "friends") {
+        id
+      }
+      cart {
+        ...CartFields
+      }
+    }
+  }
+
+  ${CART_FIELDS}
+`;
+
+const GET_CART = gql`
+  query GetCart {
+    cart @client {
+      isOpen
+    }
+  }
+`;
+
+const GET_TEAM = gql`
+  query GetTeam {
+    team {
+      id
+    }
+  }
+`;
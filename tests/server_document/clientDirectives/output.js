@@ -0,0 +1,531 @@
+import { gql } from "@apollo/client";
+const CART_FIELDS = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "FragmentDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "CartFields"
+            },
+            "directives": [],
+            "typeCondition": {
+                "kind": "NamedType",
+                "name": {
+                    "kind": "Name",
+                    "value": "Cart"
+                }
+            },
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "isOpen"
+                        },
+                        "arguments": [],
+                        "directives": [
+                            {
+                                "kind": "Directive",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "client"
+                                }
+                            }
+                        ]
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 42,
+        "source": {
+            "body": "fragment CartFields on Cart{isOpen@client}"
+        }
+    }
+};
+const GET_USER = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "GetUser"
+            },
+            "directives": [],
+            "variableDefinitions": [
+                {
+                    "kind": "VariableDefinition",
+                    "directives": [],
+                    "variable": {
+                        "kind": "Variable",
+                        "name": {
+                            "kind": "Name",
+                            "value": "id"
+                        }
+                    },
+                    "type": {
+                        "kind": "NonNullType",
+                        "type": {
+                            "kind": "NamedType",
+                            "name": {
+                                "kind": "Name",
+                                "value": "ID"
+                            }
+                        }
+                    }
+                }
+            ],
+            "operation": "query",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "currentUserId"
+                        },
+                        "arguments": [],
+                        "directives": [
+                            {
+                                "kind": "Directive",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "client"
+                                }
+                            },
+                            {
+                                "kind": "Directive",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "export"
+                                },
+                                "arguments": [
+                                    {
+                                        "kind": "Argument",
+                                        "name": {
+                                            "kind": "Name",
+                                            "value": "as"
+                                        },
+                                        "value": {
+                                            "kind": "StringValue",
+                                            "value": "id"
+                                        }
+                                    }
+                                ]
+                            }
+                        ]
+                    },
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "user"
+                        },
+                        "arguments": [
+                            {
+                                "kind": "Argument",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "id"
+                                },
+                                "value": {
+                                    "kind": "Variable",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "id"
+                                    }
+                                }
+                            }
+                        ],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "id"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                },
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "name"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                },
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "isSelected"
+                                    },
+                                    "arguments": [],
+                                    "directives": [
+                                        {
+                                            "kind": "Directive",
+                                            "name": {
+                                                "kind": "Name",
+                                                "value": "client"
+                                            }
+                                        }
+                                    ]
+                                },
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "friends"
+                                    },
+                                    "arguments": [],
+                                    "directives": [
+                                        {
+                                            "kind": "Directive",
+                                            "name": {
+                                                "kind": "Name",
+                                                "value": "connection"
+                                            },
+                                            "arguments": [
+                                                {
+                                                    "kind": "Argument",
+                                                    "name": {
+                                                        "kind": "Name",
+                                                        "value": "key"
+                                                    },
+                                                    "value": {
+                                                        "kind": "StringValue",
+                                                        "value": "friends"
+                                                    }
+                                                }
+                                            ]
+                                        }
+                                    ],
+                                    "selectionSet": {
+                                        "kind": "SelectionSet",
+                                        "selections": [
+                                            {
+                                                "kind": "Field",
+                                                "name": {
+                                                    "kind": "Name",
+                                                    "value": "id"
+                                                },
+                                                "arguments": [],
+                                                "directives": []
+                                            }
+                                        ]
+                                    }
+                                },
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "cart"
+                                    },
+                                    "arguments": [],
+                                    "directives": [],
+                                    "selectionSet": {
+                                        "kind": "SelectionSet",
+                                        "selections": [
+                                            {
+                                                "kind": "FragmentSpread",
+                                                "name": {
+                                                    "kind": "Name",
+                                                    "value": "CartFields"
+                                                },
+                                                "directives": []
+                                            }
+                                        ]
+                                    }
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        },
+        {
+            "kind": "FragmentDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "CartFields"
+            },
+            "directives": [],
+            "typeCondition": {
+                "kind": "NamedType",
+                "name": {
+                    "kind": "Name",
+                    "value": "Cart"
+                }
+            },
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "isOpen"
+                        },
+                        "arguments": [],
+                        "directives": [
+                            {
+                                "kind": "Directive",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "client"
+                                }
+                            }
+                        ]
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 198,
+        "source": {
+            "body": 'query GetUser($id:ID!){currentUserId@client@export(as:"id")user(id:$id){id name isSelected@client friends@connection(key:"friends"){id}cart{...CartFields}}}fragment CartFields on Cart{isOpen@client}'
+        }
+    },
+    "__serverDocument": {
+        "kind": "Document",
+        "definitions": [
+            {
+                "kind": "OperationDefinition",
+                "name": {
+                    "kind": "Name",
+                    "value": "GetUser"
+                },
+                "directives": [],
+                "variableDefinitions": [
+                    {
+                        "kind": "VariableDefinition",
+                        "directives": [],
+                        "variable": {
+                            "kind": "Variable",
+                            "name": {
+                                "kind": "Name",
+                                "value": "id"
+                            }
+                        },
+                        "type": {
+                            "kind": "NonNullType",
+                            "type": {
+                                "kind": "NamedType",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "ID"
+                                }
+                            }
+                        }
+                    }
+                ],
+                "operation": "query",
+                "selectionSet": {
+                    "kind": "SelectionSet",
+                    "selections": [
+                        {
+                            "kind": "Field",
+                            "name": {
+                                "kind": "Name",
+                                "value": "user"
+                            },
+                            "arguments": [
+                                {
+                                    "kind": "Argument",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "id"
+                                    },
+                                    "value": {
+                                        "kind": "Variable",
+                                        "name": {
+                                            "kind": "Name",
+                                            "value": "id"
+                                        }
+                                    }
+                                }
+                            ],
+                            "directives": [],
+                            "selectionSet": {
+                                "kind": "SelectionSet",
+                                "selections": [
+                                    {
+                                        "kind": "Field",
+                                        "name": {
+                                            "kind": "Name",
+                                            "value": "id"
+                                        },
+                                        "arguments": [],
+                                        "directives": []
+                                    },
+                                    {
+                                        "kind": "Field",
+                                        "name": {
+                                            "kind": "Name",
+                                            "value": "name"
+                                        },
+                                        "arguments": [],
+                                        "directives": []
+                                    },
+                                    {
+                                        "kind": "Field",
+                                        "name": {
+                                            "kind": "Name",
+                                            "value": "friends"
+                                        },
+                                        "arguments": [],
+                                        "directives": [],
+                                        "selectionSet": {
+                                            "kind": "SelectionSet",
+                                            "selections": [
+                                                {
+                                                    "kind": "Field",
+                                                    "name": {
+                                                        "kind": "Name",
+                                                        "value": "id"
+                                                    },
+                                                    "arguments": [],
+                                                    "directives": []
+                                                }
+                                            ]
+                                        }
+                                    }
+                                ]
+                            }
+                        }
+                    ]
+                }
+            }
+        ],
+        "loc": {
+            "start": 0,
+            "end": 57,
+            "source": {
+                "body": "query GetUser($id:ID!){user(id:$id){id name friends{id}}}"
+            }
+        }
+    }
+};
+const GET_CART = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "GetCart"
+            },
+            "directives": [],
+            "variableDefinitions": [],
+            "operation": "query",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "cart"
+                        },
+                        "arguments": [],
+                        "directives": [
+                            {
+                                "kind": "Directive",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "client"
+                                }
+                            }
+                        ],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "isOpen"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 34,
+        "source": {
+            "body": "query GetCart{cart@client{isOpen}}"
+        }
+    },
+    "__serverDocument": null
+};
+const GET_TEAM = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "GetTeam"
+            },
+            "directives": [],
+            "variableDefinitions": [],
+            "operation": "query",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "team"
+                        },
+                        "arguments": [],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "id"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 23,
+        "source": {
+            "body": "query GetTeam{team{id}}"
+        }
+    }
+};
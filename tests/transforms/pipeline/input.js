@@ -0,0 +1,60 @@
+import { gql } from "@apollo/client";
+
+const GET_USER = gql`
+  query GetUser($id: ID!) {
+    user(id: $id) {
+      id
+      isSelected @client
+      friends(first: 10) @connection(key: "friends") {
+        ...FriendFields
+      }
+    }
+  }
+
+  fragment FriendFields on User {
+    name
+  }
+
+  fragment UnusedFields on User {
+    id
+  }
+`;
+
+const MISSING_FRAGMENT = gql`
+  query GetTeam {
+    team {
+      ...TeamFields
+    }
+  }
+`;
+
+// the selections and fragments left empty are removed with the client fields
+const GET_SETTINGS = gql`
+  query GetSettings {
+    viewer {
+      id
+      settings {
+        theme @client
+      }
+      ...ClientViewerFields
+    }
+  }
+
+  fragment ClientViewerFields on Viewer {
+    isDarkMode @client
+  }
+`;
+
+// fragments only spread by client fields are removed with them
+const VIEWER_FIELDS = gql`
+  fragment ViewerFields on Viewer {
+    id
+    preferences @client {
+      ...PreferenceFields
+    }
+  }
+
+  fragment PreferenceFields on Preferences {
+    theme
+  }
+`;
@@ -0,0 +1,23 @@
+import { gql } from "@apollo/client";
+
+const QUERY = gql`
+  query search($term: String!, $filter: UserFilter) {
+    search(term: $term, filter: $filter) {
+      __typename
+      ... on Node {
+        id
+      }
+      ...UserFields
+      ... on Team {
+        members {
+          name
+        }
+      }
+    }
+  }
+
+  fragment UserFields on User {
+    name
+    role
+  }
+`;
@@ -0,0 +1,10 @@
+import { gql } from "@apollo/client";
+
+// the schema can't be parsed, documents are compiled without types
+const QUERY = gql`
+  query getUser {
+    user {
+      id
+    }
+  }
+`;
@@ -0,0 +1,33 @@
+import { gql } from "@apollo/client";
+
+// should not be typed, TypedDocumentNode describes a single operation
+const QUERIES = gql`
+  query first {
+    user(id: "1") {
+      id
+    }
+  }
+
+  query second {
+    user(id: "2") {
+      id
+    }
+  }
+`;
+
+const FRAGMENT = gql`
+  fragment UserFields on User {
+    name
+  }
+`;
+
+// should not be typed, interpolated fragments are unknown at compile time
+const QUERY = gql`
+  query third {
+    user(id: "3") {
+      ...UserFields
+    }
+  }
+
+  ${FRAGMENT}
+`;
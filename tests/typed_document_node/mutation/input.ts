@@ -0,0 +1,10 @@
+import { gql } from "@apollo/client";
+
+const MUTATION = gql`
+  mutation renameUser($id: ID!, $name: String!) {
+    renamed: renameUser(id: $id, name: $name) {
+      id
+      name
+    }
+  }
+`;
@@ -0,0 +1,16 @@
+import { gql } from "@apollo/client";
+
+const QUERY = gql`
+  query getUser($id: ID!, $first: Int = 10) {
+    user(id: $id) {
+      __typename
+      id
+      name
+      role
+      createdAt
+      friends(first: $first) @include(if: true) {
+        id
+      }
+    }
+  }
+`;
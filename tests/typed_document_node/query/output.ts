@@ -0,0 +1,219 @@
+import { gql } from "@apollo/client";
+const QUERY = {
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "OperationDefinition",
+            "name": {
+                "kind": "Name",
+                "value": "getUser"
+            },
+            "directives": [],
+            "variableDefinitions": [
+                {
+                    "kind": "VariableDefinition",
+                    "directives": [],
+                    "variable": {
+                        "kind": "Variable",
+                        "name": {
+                            "kind": "Name",
+                            "value": "id"
+                        }
+                    },
+                    "type": {
+                        "kind": "NonNullType",
+                        "type": {
+                            "kind": "NamedType",
+                            "name": {
+                                "kind": "Name",
+                                "value": "ID"
+                            }
+                        }
+                    }
+                },
+                {
+                    "kind": "VariableDefinition",
+                    "directives": [],
+                    "variable": {
+                        "kind": "Variable",
+                        "name": {
+                            "kind": "Name",
+                            "value": "first"
+                        }
+                    },
+                    "type": {
+                        "kind": "NamedType",
+                        "name": {
+                            "kind": "Name",
+                            "value": "Int"
+                        }
+                    },
+                    "defaultValue": {
+                        "kind": "IntValue",
+                        "value": "10"
+                    }
+                }
+            ],
+            "operation": "query",
+            "selectionSet": {
+                "kind": "SelectionSet",
+                "selections": [
+                    {
+                        "kind": "Field",
+                        "name": {
+                            "kind": "Name",
+                            "value": "user"
+                        },
+                        "arguments": [
+                            {
+                                "kind": "Argument",
+                                "name": {
+                                    "kind": "Name",
+                                    "value": "id"
+                                },
+                                "value": {
+                                    "kind": "Variable",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "id"
+                                    }
+                                }
+                            }
+                        ],
+                        "directives": [],
+                        "selectionSet": {
+                            "kind": "SelectionSet",
+                            "selections": [
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "__typename"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                },
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "id"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                },
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "name"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                },
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "role"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                },
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "createdAt"
+                                    },
+                                    "arguments": [],
+                                    "directives": []
+                                },
+                                {
+                                    "kind": "Field",
+                                    "name": {
+                                        "kind": "Name",
+                                        "value": "friends"
+                                    },
+                                    "arguments": [
+                                        {
+                                            "kind": "Argument",
+                                            "name": {
+                                                "kind": "Name",
+                                                "value": "first"
+                                            },
+                                            "value": {
+                                                "kind": "Variable",
+                                                "name": {
+                                                    "kind": "Name",
+                                                    "value": "first"
+                                                }
+                                            }
+                                        }
+                                    ],
+                                    "directives": [
+                                        {
+                                            "kind": "Directive",
+                                            "name": {
+                                                "kind": "Name",
+                                                "value": "include"
+                                            },
+                                            "arguments": [
+                                                {
+                                                    "kind": "Argument",
+                                                    "name": {
+                                                        "kind": "Name",
+                                                        "value": "if"
+                                                    },
+                                                    "value": {
+                                                        "kind": "BooleanValue",
+                                                        "value": true
+                                                    }
+                                                }
+                                            ]
+                                        }
+                                    ],
+                                    "selectionSet": {
+                                        "kind": "SelectionSet",
+                                        "selections": [
+                                            {
+                                                "kind": "Field",
+                                                "name": {
+                                                    "kind": "Name",
+                                                    "value": "id"
+                                                },
+                                                "arguments": [],
+                                                "directives": []
+                                            }
+                                        ]
+                                    }
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }
+    ],
+    "loc": {
+        "start": 0,
+        "end": 210,
+        "source": {
+            "body": "\n  query getUser($id: ID!, $first: Int = 10) {\n    user(id: $id) {\n      __typename\n      id\n      name\n      role\n      createdAt\n      friends(first: $first) @include(if: true) {\n        id\n      }\n    }\n  }\n"
+        }
+    }
+} as import("@graphql-typed-document-node/core").TypedDocumentNode<{
+    user: {
+        __typename: "User";
+        id: string;
+        name: string | null;
+        role: "ADMIN" | "MEMBER";
+        createdAt: string;
+        friends?: Array<{
+            id: string;
+        }>;
+    } | null;
+}, {
+    id: string;
+    first?: number | null;
+}>;
@@ -0,0 +1,210 @@
+// libs
+use apollo_parser::cst::{self, CstNode};
+
+// helpers
+use crate::validation::create_error;
+
+// structs
+use crate::ast::*;
+use crate::structs::GraphQLError;
+
+fn lower_name(name: Option<cst::Name>) -> String {
+    name.unwrap().text().to_string()
+}
+
+fn lower_operation_type(operation_type: Option<cst::OperationType>) -> OperationType {
+    match operation_type {
+        Some(operation_type) if operation_type.mutation_token().is_some() => {
+            OperationType::Mutation
+        }
+        Some(operation_type) if operation_type.subscription_token().is_some() => {
+            OperationType::Subscription
+        }
+        _ => OperationType::Query,
+    }
+}
+
+fn lower_directives(directives: Option<cst::Directives>) -> Vec<Directive> {
+    let Some(directives) = directives else {
+        return vec![];
+    };
+
+    directives
+        .directives()
+        .map(|directive| Directive {
+            name: lower_name(directive.name()),
+            arguments: directive
+                .arguments()
+                .map(|arguments| lower_arguments(Some(arguments))),
+        })
+        .collect()
+}
+
+fn lower_arguments(arguments: Option<cst::Arguments>) -> Vec<Argument> {
+    let Some(arguments) = arguments else {
+        return vec![];
+    };
+
+    arguments
+        .arguments()
+        .map(|argument| Argument {
+            name: lower_name(argument.name()),
+            value: lower_value(argument.value()),
+        })
+        .collect()
+}
+
+fn lower_string_value(string_value: cst::StringValue) -> Value {
+    // apollo-parser unescapes both kinds of strings, the printer still
+    // needs to know which one it was given
+    let block = string_value
+        .syntax()
+        .first_token()
+        .is_some_and(|token| token.text().starts_with("\"\"\""));
+    Value::String {
+        value: String::from(&string_value),
+        block,
+    }
+}
+
+fn lower_value(value: Option<cst::Value>) -> Value {
+    assert!(value.is_some());
+    match value.unwrap() {
+        cst::Value::Variable(variable) => Value::Variable(lower_name(variable.name())),
+        cst::Value::StringValue(string_value) => lower_string_value(string_value),
+        cst::Value::FloatValue(float) => Value::Float(float.float_token().unwrap().text().into()),
+        cst::Value::IntValue(int) => Value::Int(int.int_token().unwrap().text().into()),
+        cst::Value::BooleanValue(boolean) => Value::Boolean(boolean.true_token().is_some()),
+        cst::Value::NullValue(_) => Value::Null,
+        cst::Value::EnumValue(enum_value) => Value::Enum(enum_value.text().to_string()),
+        cst::Value::ListValue(list) => Value::List(
+            list.values()
+                .map(|value| lower_value(Some(value)))
+                .collect(),
+        ),
+        cst::Value::ObjectValue(object) => Value::Object(
+            object
+                .object_fields()
+                .map(|field| ObjectField {
+                    name: lower_name(field.name()),
+                    value: lower_value(field.value()),
+                })
+                .collect(),
+        ),
+    }
+}
+
+fn lower_type(ty: cst::Type) -> Type {
+    match ty {
+        cst::Type::NamedType(named_type) => Type::Named(lower_name(named_type.name())),
+        cst::Type::ListType(list_type) => {
+            Type::List(list_type.ty().map(|ty| Box::new(lower_type(ty))))
+        }
+        cst::Type::NonNullType(non_null_type) => {
+            Type::NonNull(Box::new(match non_null_type.named_type() {
+                Some(named_type) => Type::Named(lower_name(named_type.name())),
+                None => lower_type(cst::Type::ListType(non_null_type.list_type().unwrap())),
+            }))
+        }
+    }
+}
+
+fn lower_type_condition(type_condition: Option<cst::TypeCondition>) -> Option<String> {
+    type_condition.map(|type_condition| lower_name(type_condition.named_type().unwrap().name()))
+}
+
+fn lower_variable_definitions(
+    variable_definitions: Option<cst::VariableDefinitions>,
+) -> Vec<VariableDefinition> {
+    let Some(variable_definitions) = variable_definitions else {
+        return vec![];
+    };
+
+    variable_definitions
+        .variable_definitions()
+        .map(|variable_definition| VariableDefinition {
+            variable: lower_name(variable_definition.variable().unwrap().name()),
+            ty: variable_definition.ty().map(lower_type),
+            default_value: variable_definition
+                .default_value()
+                .map(|default_value| lower_value(default_value.value())),
+            directives: lower_directives(variable_definition.directives()),
+        })
+        .collect()
+}
+
+fn lower_selection_set(selection_set: Option<cst::SelectionSet>) -> Option<SelectionSet> {
+    let selection_set = selection_set?;
+
+    Some(SelectionSet {
+        selections: selection_set
+            .selections()
+            .map(|selection| match selection {
+                cst::Selection::Field(field) => Selection::Field(Field {
+                    alias: field.alias().map(|alias| lower_name(alias.name())),
+                    name: lower_name(field.name()),
+                    arguments: lower_arguments(field.arguments()),
+                    directives: lower_directives(field.directives()),
+                    selection_set: lower_selection_set(field.selection_set()),
+                }),
+                cst::Selection::FragmentSpread(fragment_spread) => {
+                    Selection::FragmentSpread(FragmentSpread {
+                        name: lower_name(fragment_spread.fragment_name().unwrap().name()),
+                        directives: lower_directives(fragment_spread.directives()),
+                    })
+                }
+                cst::Selection::InlineFragment(inline_fragment) => {
+                    Selection::InlineFragment(InlineFragment {
+                        type_condition: lower_type_condition(inline_fragment.type_condition()),
+                        directives: lower_directives(inline_fragment.directives()),
+                        selection_set: lower_selection_set(inline_fragment.selection_set()),
+                    })
+                }
+            })
+            .collect(),
+    })
+}
+
+fn lower_definition(definition: cst::Definition) -> Result<Definition, GraphQLError> {
+    match definition {
+        cst::Definition::OperationDefinition(operation) => {
+            Ok(Definition::Operation(OperationDefinition {
+                operation: lower_operation_type(operation.operation_type()),
+                name: operation.name().map(|name| name.text().to_string()),
+                variable_definitions: lower_variable_definitions(operation.variable_definitions()),
+                directives: lower_directives(operation.directives()),
+                selection_set: lower_selection_set(operation.selection_set()),
+            }))
+        }
+        cst::Definition::FragmentDefinition(fragment) => {
+            Ok(Definition::Fragment(FragmentDefinition {
+                name: lower_name(fragment.fragment_name().unwrap().name()),
+                type_condition: lower_type_condition(fragment.type_condition()),
+                directives: lower_directives(fragment.directives()),
+                selection_set: lower_selection_set(fragment.selection_set()),
+            }))
+        }
+        definition => Err(create_error(
+            "Type system definitions are not supported in documents".into(),
+            &definition,
+        )),
+    }
+}
+
+pub fn lower_document(document: &cst::Document) -> Result<Document, Vec<GraphQLError>> {
+    let mut definitions = vec![];
+    let mut errors = vec![];
+
+    for definition in document.definitions() {
+        match lower_definition(definition) {
+            Ok(definition) => definitions.push(definition),
+            Err(error) => errors.push(error),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(Document { definitions })
+    } else {
+        Err(errors)
+    }
+}
@@ -0,0 +1,181 @@
+// modules
+mod lower;
+mod printer;
+mod utils;
+
+// re-exports
+pub use lower::lower_document;
+pub use printer::{print_document, print_selection};
+pub use utils::{get_reachable_fragments, merge_documents, split_operations};
+
+// Owned GraphQL document nodes mirroring graphql-js's `DocumentNode`, which
+// passes can inspect and rewrite before the document is emitted as JS.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Document {
+    pub definitions: Vec<Definition>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Definition {
+    Operation(OperationDefinition),
+    Fragment(FragmentDefinition),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OperationType {
+    Query,
+    Mutation,
+    Subscription,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct OperationDefinition {
+    pub operation: OperationType,
+    pub name: Option<String>,
+    pub variable_definitions: Vec<VariableDefinition>,
+    pub directives: Vec<Directive>,
+    pub selection_set: Option<SelectionSet>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FragmentDefinition {
+    pub name: String,
+    pub type_condition: Option<String>,
+    pub directives: Vec<Directive>,
+    pub selection_set: Option<SelectionSet>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct VariableDefinition {
+    pub variable: String,
+    pub ty: Option<Type>,
+    pub default_value: Option<Value>,
+    pub directives: Vec<Directive>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Type {
+    Named(String),
+    List(Option<Box<Type>>),
+    NonNull(Box<Type>),
+}
+
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct SelectionSet {
+    pub selections: Vec<Selection>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Selection {
+    Field(Field),
+    FragmentSpread(FragmentSpread),
+    InlineFragment(InlineFragment),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Field {
+    pub alias: Option<String>,
+    pub name: String,
+    pub arguments: Vec<Argument>,
+    pub directives: Vec<Directive>,
+    pub selection_set: Option<SelectionSet>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FragmentSpread {
+    pub name: String,
+    pub directives: Vec<Directive>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InlineFragment {
+    pub type_condition: Option<String>,
+    pub directives: Vec<Directive>,
+    pub selection_set: Option<SelectionSet>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Directive {
+    pub name: String,
+    pub arguments: Option<Vec<Argument>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Argument {
+    pub name: String,
+    pub value: Value,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Variable(String),
+    String { value: String, block: bool },
+    Float(String),
+    Int(String),
+    Boolean(bool),
+    Null,
+    Enum(String),
+    List(Vec<Value>),
+    Object(Vec<ObjectField>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ObjectField {
+    pub name: String,
+    pub value: Value,
+}
+
+impl OperationType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OperationType::Query => "query",
+            OperationType::Mutation => "mutation",
+            OperationType::Subscription => "subscription",
+        }
+    }
+}
+
+/// Collects the names of fragments spread in a selection set, including
+/// nested selection sets but not the fragments themselves.
+pub fn get_fragment_spreads(selection_set: &SelectionSet, spreads: &mut Vec<String>) {
+    for selection in &selection_set.selections {
+        match selection {
+            Selection::Field(field) => {
+                if let Some(nested) = &field.selection_set {
+                    get_fragment_spreads(nested, spreads);
+                }
+            }
+            Selection::InlineFragment(inline_fragment) => {
+                if let Some(nested) = &inline_fragment.selection_set {
+                    get_fragment_spreads(nested, spreads);
+                }
+            }
+            Selection::FragmentSpread(spread) => spreads.push(spread.name.clone()),
+        }
+    }
+}
+
+/// Returns the names of fragments spread in `document`, by its operations
+/// or fragments, that none of its fragments define.
+pub fn get_unknown_fragments(document: &Document) -> Vec<String> {
+    let mut fragment_names = vec![];
+    let mut spreads = vec![];
+    for definition in &document.definitions {
+        let selection_set = match definition {
+            Definition::Operation(operation) => operation.selection_set.as_ref(),
+            Definition::Fragment(fragment) => {
+                fragment_names.push(fragment.name.as_str());
+                fragment.selection_set.as_ref()
+            }
+        };
+        if let Some(selection_set) = selection_set {
+            get_fragment_spreads(selection_set, &mut spreads);
+        }
+    }
+
+    spreads
+        .into_iter()
+        .filter(|spread| !fragment_names.contains(&spread.as_str()))
+        .collect()
+}
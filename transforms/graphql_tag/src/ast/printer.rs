@@ -0,0 +1,271 @@
+// structs
+use crate::ast::*;
+
+// Prints documents the way graphql-js's `print` does, so the printed text
+// matches what clients send and hash at runtime.
+
+fn join(parts: &[String], separator: &str) -> String {
+    parts
+        .iter()
+        .filter(|part| !part.is_empty())
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+fn wrap(start: &str, middle: &str, end: &str) -> String {
+    if middle.is_empty() {
+        String::new()
+    } else {
+        format!("{}{}{}", start, middle, end)
+    }
+}
+
+fn indent(text: &str) -> String {
+    wrap("  ", &text.replace('\n', "\n  "), "")
+}
+
+fn block(parts: &[String]) -> String {
+    wrap("{\n", &indent(&join(parts, "\n")), "\n}")
+}
+
+fn print_string(value: &str) -> String {
+    let mut printed = String::from('"');
+    for char in value.chars() {
+        match char {
+            '"' => printed.push_str("\\\""),
+            '\\' => printed.push_str("\\\\"),
+            '\u{0008}' => printed.push_str("\\b"),
+            '\t' => printed.push_str("\\t"),
+            '\n' => printed.push_str("\\n"),
+            '\u{000c}' => printed.push_str("\\f"),
+            '\r' => printed.push_str("\\r"),
+            '\u{0000}'..='\u{001f}' | '\u{007f}'..='\u{009f}' => {
+                printed.push_str(&format!("\\u{:04X}", char as u32))
+            }
+            _ => printed.push(char),
+        }
+    }
+    printed.push('"');
+    printed
+}
+
+fn print_block_string(value: &str) -> String {
+    let is_white_space = |line: &str| line.starts_with([' ', '\t']);
+    let escaped = value.replace("\"\"\"", "\\\"\"\"");
+    let lines: Vec<&str> = escaped
+        .split("\r\n")
+        .flat_map(|line| line.split(['\n', '\r']))
+        .collect();
+    let is_single_line = lines.len() == 1;
+    let force_leading_new_line = lines.len() > 1
+        && lines[1..]
+            .iter()
+            .all(|line| line.is_empty() || is_white_space(line));
+    let has_trailing_triple_quotes = escaped.ends_with("\\\"\"\"");
+    let has_trailing_quote = value.ends_with('"') && !has_trailing_triple_quotes;
+    let has_trailing_slash = value.ends_with('\\');
+    let force_trailing_new_line = has_trailing_quote || has_trailing_slash;
+    let print_as_multiple_lines = !is_single_line
+        || value.encode_utf16().count() > 70
+        || force_trailing_new_line
+        || force_leading_new_line
+        || has_trailing_triple_quotes;
+    let skip_leading_new_line = is_single_line && is_white_space(value);
+
+    let mut printed = String::from("\"\"\"");
+    if (print_as_multiple_lines && !skip_leading_new_line) || force_leading_new_line {
+        printed.push('\n');
+    }
+    printed.push_str(&escaped);
+    if print_as_multiple_lines || force_trailing_new_line {
+        printed.push('\n');
+    }
+    printed.push_str("\"\"\"");
+    printed
+}
+
+fn print_value(value: &Value) -> String {
+    match value {
+        Value::Variable(name) => format!("${}", name),
+        Value::String { value, block } => {
+            if *block {
+                print_block_string(value)
+            } else {
+                print_string(value)
+            }
+        }
+        Value::Float(value) | Value::Int(value) | Value::Enum(value) => value.clone(),
+        Value::Boolean(value) => value.to_string(),
+        Value::Null => "null".into(),
+        Value::List(values) => {
+            let values: Vec<String> = values.iter().map(print_value).collect();
+            format!("[{}]", join(&values, ", "))
+        }
+        Value::Object(fields) => {
+            let fields: Vec<String> = fields
+                .iter()
+                .map(|field| format!("{}: {}", field.name, print_value(&field.value)))
+                .collect();
+            format!("{{{}}}", join(&fields, ", "))
+        }
+    }
+}
+
+fn print_arguments(arguments: &[Argument]) -> Vec<String> {
+    arguments
+        .iter()
+        .map(|argument| format!("{}: {}", argument.name, print_value(&argument.value)))
+        .collect()
+}
+
+fn print_directives(directives: &[Directive]) -> String {
+    let directives: Vec<String> = directives
+        .iter()
+        .map(|directive| {
+            let arguments = print_arguments(directive.arguments.as_deref().unwrap_or_default());
+            format!(
+                "@{}{}",
+                directive.name,
+                wrap("(", &join(&arguments, ", "), ")")
+            )
+        })
+        .collect();
+    join(&directives, " ")
+}
+
+fn print_type(ty: &Type) -> String {
+    match ty {
+        Type::Named(name) => name.clone(),
+        Type::List(ty) => format!("[{}]", ty.as_deref().map(print_type).unwrap_or_default()),
+        Type::NonNull(ty) => format!("{}!", print_type(ty)),
+    }
+}
+
+fn print_variable_definitions(variable_definitions: &[VariableDefinition]) -> String {
+    let variable_definitions: Vec<String> = variable_definitions
+        .iter()
+        .map(|variable_definition| {
+            let ty = variable_definition
+                .ty
+                .as_ref()
+                .map(print_type)
+                .unwrap_or_default();
+            let default_value = variable_definition
+                .default_value
+                .as_ref()
+                .map(print_value)
+                .unwrap_or_default();
+            format!(
+                "${}: {}{}{}",
+                variable_definition.variable,
+                ty,
+                wrap(" = ", &default_value, ""),
+                wrap(" ", &print_directives(&variable_definition.directives), "")
+            )
+        })
+        .collect();
+    wrap("(", &join(&variable_definitions, ", "), ")")
+}
+
+/// Prints a selection along with its own selection set.
+pub fn print_selection(selection: &Selection) -> String {
+    match selection {
+        Selection::Field(field) => {
+            let prefix = format!(
+                "{}{}",
+                wrap("", field.alias.as_deref().unwrap_or_default(), ": "),
+                field.name
+            );
+            let arguments = print_arguments(&field.arguments);
+            let mut arguments_line =
+                format!("{}{}", prefix, wrap("(", &join(&arguments, ", "), ")"));
+            if arguments_line.encode_utf16().count() > 80 {
+                arguments_line = format!(
+                    "{}{}",
+                    prefix,
+                    wrap("(\n", &indent(&join(&arguments, "\n")), "\n)")
+                );
+            }
+            join(
+                &[
+                    arguments_line,
+                    print_directives(&field.directives),
+                    print_selection_set(field.selection_set.as_ref()),
+                ],
+                " ",
+            )
+        }
+        Selection::FragmentSpread(spread) => format!(
+            "...{}{}",
+            spread.name,
+            wrap(" ", &print_directives(&spread.directives), "")
+        ),
+        Selection::InlineFragment(inline_fragment) => join(
+            &[
+                "...".into(),
+                wrap(
+                    "on ",
+                    inline_fragment
+                        .type_condition
+                        .as_deref()
+                        .unwrap_or_default(),
+                    "",
+                ),
+                print_directives(&inline_fragment.directives),
+                print_selection_set(inline_fragment.selection_set.as_ref()),
+            ],
+            " ",
+        ),
+    }
+}
+
+fn print_selection_set(selection_set: Option<&SelectionSet>) -> String {
+    let Some(selection_set) = selection_set else {
+        return String::new();
+    };
+
+    let selections: Vec<String> = selection_set
+        .selections
+        .iter()
+        .map(print_selection)
+        .collect();
+    block(&selections)
+}
+
+fn print_definition(definition: &Definition) -> String {
+    match definition {
+        Definition::Operation(operation) => {
+            let prefix = join(
+                &[
+                    operation.operation.as_str().into(),
+                    format!(
+                        "{}{}",
+                        operation.name.as_deref().unwrap_or_default(),
+                        print_variable_definitions(&operation.variable_definitions)
+                    ),
+                    print_directives(&operation.directives),
+                ],
+                " ",
+            );
+            let selection_set = print_selection_set(operation.selection_set.as_ref());
+            if prefix == "query" {
+                selection_set
+            } else {
+                format!("{} {}", prefix, selection_set)
+            }
+        }
+        Definition::Fragment(fragment) => format!(
+            "fragment {} on {} {}{}",
+            fragment.name,
+            fragment.type_condition.as_deref().unwrap_or_default(),
+            wrap("", &print_directives(&fragment.directives), " "),
+            print_selection_set(fragment.selection_set.as_ref())
+        ),
+    }
+}
+
+pub fn print_document(document: &Document) -> String {
+    let definitions: Vec<String> = document.definitions.iter().map(print_definition).collect();
+    join(&definitions, "\n\n")
+}
@@ -0,0 +1,97 @@
+// built-ins
+use std::collections::{HashMap, HashSet};
+
+// helpers
+use super::get_fragment_spreads;
+
+// structs
+use super::{Definition, Document};
+
+pub fn get_reachable_fragments(
+    fragment_spreads: &HashMap<String, Vec<String>>,
+    mut pending: Vec<String>,
+) -> HashSet<String> {
+    let mut reachable = HashSet::new();
+    while let Some(spread) = pending.pop() {
+        if !reachable.insert(spread.clone()) {
+            continue;
+        }
+        if let Some(spreads) = fragment_spreads.get(&spread) {
+            pending.extend(spreads.iter().cloned());
+        }
+    }
+    reachable
+}
+
+/// Appends the definitions of interpolated documents to `document`, skipping
+/// fragments that are already defined, like graphql-tag does at runtime.
+pub fn merge_documents(document: &mut Document, sources: Vec<Document>) {
+    let mut fragment_names: HashSet<String> = document
+        .definitions
+        .iter()
+        .filter_map(|definition| match definition {
+            Definition::Fragment(fragment) => Some(fragment.name.clone()),
+            Definition::Operation(_) => None,
+        })
+        .collect();
+
+    for source in sources {
+        for definition in source.definitions {
+            if let Definition::Fragment(fragment) = &definition {
+                if !fragment_names.insert(fragment.name.clone()) {
+                    continue;
+                }
+            }
+            document.definitions.push(definition);
+        }
+    }
+}
+
+/// Splits `document` into one document per named operation, containing the
+/// operation and the fragments it transitively spreads, like
+/// graphql-tag/loader does for the named exports of `.graphql` files.
+pub fn split_operations(document: &Document) -> Vec<(String, Document)> {
+    let mut fragment_spreads = HashMap::new();
+    for definition in &document.definitions {
+        if let Definition::Fragment(fragment) = definition {
+            let mut spreads = vec![];
+            if let Some(selection_set) = &fragment.selection_set {
+                get_fragment_spreads(selection_set, &mut spreads);
+            }
+            fragment_spreads
+                .entry(fragment.name.clone())
+                .or_insert(spreads);
+        }
+    }
+
+    document
+        .definitions
+        .iter()
+        .filter_map(|definition| match definition {
+            Definition::Operation(operation) => {
+                let name = operation.name.clone()?;
+
+                let mut spreads = vec![];
+                if let Some(selection_set) = &operation.selection_set {
+                    get_fragment_spreads(selection_set, &mut spreads);
+                }
+                let used_fragments = get_reachable_fragments(&fragment_spreads, spreads);
+
+                let mut definitions = vec![definition.clone()];
+                for definition in &document.definitions {
+                    match definition {
+                        Definition::Fragment(fragment)
+                            if used_fragments.contains(&fragment.name) =>
+                        {
+                            definitions.push(definition.clone())
+                        }
+                        _ => {}
+                    }
+                }
+
+                Some((name, Document { definitions }))
+            }
+            Definition::Fragment(_) => None,
+        })
+        .collect()
+}
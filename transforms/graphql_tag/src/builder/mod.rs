@@ -0,0 +1,209 @@
+// built-ins
+use std::{collections::HashMap, sync::Arc};
+
+// libs
+use swc_common::comments::Comments;
+use swc_ecma_ast::{Module, Program, Script};
+use swc_ecma_visit::{as_folder, Fold, FoldWith};
+
+// structs
+use crate::structs::{
+    CommentTemplates, DirectivesConfig, GraphQLTagConfig, LimitsConfig, LintConfig, OutputFormat,
+    OutputProfile, TransformVisitor,
+};
+use crate::transform::DocumentTransform;
+use unique_identifier::UniqueIdentifierVisitor;
+
+/// Options of the transform, defaulting to the plugin's defaults.
+///
+/// ```ignore
+/// let pass = graphql_tag::transform(
+///     GraphQLTagOptions::new().strip(true).file_path("src/queries.ts"),
+///     comments.clone(),
+/// );
+/// let program = program.fold_with(&mut pass);
+/// ```
+#[derive(Clone, Default)]
+pub struct GraphQLTagOptions {
+    config: GraphQLTagConfig,
+}
+
+impl GraphQLTagOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn import_sources(mut self, import_sources: Vec<String>) -> Self {
+        self.config.import_sources = import_sources;
+        self
+    }
+
+    pub fn gql_tag_identifiers(mut self, gql_tag_identifiers: Vec<String>) -> Self {
+        self.config.gql_tag_identifiers = gql_tag_identifiers;
+        self
+    }
+
+    pub fn strip(mut self, strip: bool) -> Self {
+        self.config.strip = strip;
+        self
+    }
+
+    pub fn output(mut self, output: OutputFormat) -> Self {
+        self.config.output = output;
+        self
+    }
+
+    pub fn schema(mut self, schema: impl Into<String>) -> Self {
+        self.config.schema = Some(schema.into());
+        self
+    }
+
+    pub fn scalars(mut self, scalars: HashMap<String, String>) -> Self {
+        self.config.scalars = scalars;
+        self
+    }
+
+    pub fn lint(mut self, lint: LintConfig) -> Self {
+        self.config.lint = lint;
+        self
+    }
+
+    pub fn directives(mut self, directives: DirectivesConfig) -> Self {
+        self.config.directives = Some(directives);
+        self
+    }
+
+    pub fn limits(mut self, limits: LimitsConfig) -> Self {
+        self.config.limits = limits;
+        self
+    }
+
+    pub fn resolve_imports(mut self, resolve_imports: bool) -> Self {
+        self.config.resolve_imports = resolve_imports;
+        self
+    }
+
+    pub fn prune_unused_fragments(mut self, prune_unused_fragments: bool) -> Self {
+        self.config.prune_unused_fragments = prune_unused_fragments;
+        self
+    }
+
+    pub fn inline_fragments(mut self, inline_fragments: bool) -> Self {
+        self.config.inline_fragments = inline_fragments;
+        self
+    }
+
+    pub fn normalize(mut self, normalize: bool) -> Self {
+        self.config.normalize = normalize;
+        self
+    }
+
+    pub fn server_document(mut self, server_document: bool) -> Self {
+        self.config.server_document = server_document;
+        self
+    }
+
+    pub fn comment_templates(mut self, comment_templates: CommentTemplates) -> Self {
+        self.config.comment_templates = comment_templates;
+        self
+    }
+
+    /// Sets what the gql tag of a library returns, recognizing the tags it
+    /// exports even when it isn't one of `import_sources`.
+    pub fn output_profile(
+        mut self,
+        import_source: impl Into<String>,
+        profile: OutputProfile,
+    ) -> Self {
+        self.config
+            .output_profiles
+            .insert(import_source.into(), profile);
+        self
+    }
+
+    /// Adds a pass run after the ones added before it, created by `factory`
+    /// for every program.
+    pub fn transform(mut self, factory: impl Fn() -> Box<dyn DocumentTransform> + 'static) -> Self {
+        self.config.transforms.push(Arc::new(factory));
+        self
+    }
+
+    /// Path of the transformed file, used to report diagnostics and to
+    /// resolve `#import`s, imported documents and the kind of file.
+    pub fn file_path(mut self, file_path: impl Into<String>) -> Self {
+        self.config.file_path = file_path.into();
+        self
+    }
+
+    /// The config the options amount to, as the visitor and extraction take it.
+    pub fn config(&self) -> &GraphQLTagConfig {
+        &self.config
+    }
+}
+
+/// What the pass reported over the programs it folded so far.
+#[derive(Default)]
+pub struct TransformReport {
+    /// Compiled GraphQL text of every document.
+    pub documents: Vec<String>,
+    pub error_count: usize,
+}
+
+/// The pass created by [`transform`], compiling the gql documents of every
+/// program it folds.
+pub struct GraphQLTagFolder<C: Comments + Clone> {
+    config: GraphQLTagConfig,
+    comments: C,
+    report: TransformReport,
+}
+
+impl<C: Comments + Clone> GraphQLTagFolder<C> {
+    /// Returns what was reported since the last call.
+    pub fn take_report(&mut self) -> TransformReport {
+        std::mem::take(&mut self.report)
+    }
+}
+
+impl<C: Comments + Clone> Fold for GraphQLTagFolder<C> {
+    fn fold_program(&mut self, program: Program) -> Program {
+        let mut unique_visitor = UniqueIdentifierVisitor::new();
+        let program = program.fold_with(&mut as_folder(&mut unique_visitor));
+
+        // the visitor keeps per program state, like the documents it compiled
+        let mut visitor = TransformVisitor::new(self.config.clone(), self.comments.clone());
+        visitor.unique_fn_name = unique_visitor.unique_name();
+        let program = program.fold_with(&mut as_folder(&mut visitor));
+
+        self.report.documents.extend(visitor.documents);
+        self.report.error_count += visitor.error_count;
+        program
+    }
+
+    fn fold_module(&mut self, module: Module) -> Module {
+        match self.fold_program(Program::Module(module)) {
+            Program::Module(module) => module,
+            Program::Script(_) => unreachable!(),
+        }
+    }
+
+    fn fold_script(&mut self, script: Script) -> Script {
+        match self.fold_program(Program::Script(script)) {
+            Program::Script(script) => script,
+            Program::Module(_) => unreachable!(),
+        }
+    }
+}
+
+/// Creates the pass compiling the gql documents of programs, naming the
+/// helper function it may add so it doesn't clash with each program's own
+/// identifiers.
+pub fn transform<C: Comments + Clone>(
+    options: GraphQLTagOptions,
+    comments: C,
+) -> GraphQLTagFolder<C> {
+    GraphQLTagFolder {
+        config: options.config,
+        comments,
+        report: TransformReport::default(),
+    }
+}
@@ -0,0 +1,175 @@
+// libs
+use swc_common::{Span, Spanned};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+// helpers
+use crate::ast::{get_fragment_spreads, lower_document};
+use crate::parser::parse_graphql_tag;
+use crate::utils::get_gql_tag_local_names;
+
+// structs
+use crate::ast::{Definition, Document, OperationType};
+use crate::structs::{GraphQLError, GraphQLTagConfig};
+
+pub struct ExtractedOperation {
+    pub name: Option<String>,
+    pub operation: OperationType,
+}
+
+pub struct Interpolation {
+    pub span: Span,
+    // set when the interpolated expression is a plain identifier
+    pub identifier: Option<String>,
+}
+
+/// A gql template of a program as written in the source, before any
+/// interpolation is resolved or the document is compiled.
+pub struct ExtractedDocument {
+    pub span: Span,
+    pub declarator: Option<String>,
+    /// Cooked text of the template, like the strings graphql-tag gets at
+    /// runtime, without its interpolations.
+    pub text: String,
+    // `None` when the text doesn't parse, with the reasons in `errors`
+    pub document: Option<Document>,
+    pub errors: Vec<GraphQLError>,
+    pub operations: Vec<ExtractedOperation>,
+    pub fragments: Vec<String>,
+    // fragments spread in the document but defined elsewhere
+    pub fragment_dependencies: Vec<String>,
+    pub interpolations: Vec<Interpolation>,
+}
+
+struct Extractor<'a> {
+    config: &'a GraphQLTagConfig,
+    active_gql_tag_identifiers: Vec<String>,
+    pending_declarator: Option<String>,
+    documents: Vec<ExtractedDocument>,
+}
+
+impl<'a> Extractor<'a> {
+    fn is_gql_tag(&self, tag: &Expr) -> bool {
+        tag.as_ident().is_some_and(|tag| {
+            self.active_gql_tag_identifiers
+                .contains(&tag.sym.to_string())
+        })
+    }
+}
+
+fn extract_document(tag_tpl: &TaggedTpl, declarator: Option<String>) -> ExtractedDocument {
+    // quasis with invalid escapes have no cooked text, only tagged
+    // templates allow them
+    let text: String = tag_tpl
+        .tpl
+        .quasis
+        .iter()
+        .map(|quasi| quasi.cooked.as_ref().unwrap_or(&quasi.raw).to_string())
+        .collect();
+
+    let interpolations = tag_tpl
+        .tpl
+        .exprs
+        .iter()
+        .map(|expr| Interpolation {
+            span: expr.span(),
+            identifier: expr.as_ident().map(|ident| ident.sym.to_string()),
+        })
+        .collect();
+
+    let mut extracted_document = ExtractedDocument {
+        span: tag_tpl.span,
+        declarator,
+        text,
+        document: None,
+        errors: vec![],
+        operations: vec![],
+        fragments: vec![],
+        fragment_dependencies: vec![],
+        interpolations,
+    };
+
+    let document =
+        parse_graphql_tag(&extracted_document.text).and_then(|document| lower_document(&document));
+    let document = match document {
+        Ok(document) => document,
+        Err(errors) => {
+            extracted_document.errors = errors;
+            return extracted_document;
+        }
+    };
+
+    let mut spreads = vec![];
+    for definition in &document.definitions {
+        let selection_set = match definition {
+            Definition::Operation(operation) => {
+                extracted_document.operations.push(ExtractedOperation {
+                    name: operation.name.clone(),
+                    operation: operation.operation,
+                });
+                operation.selection_set.as_ref()
+            }
+            Definition::Fragment(fragment) => {
+                extracted_document.fragments.push(fragment.name.clone());
+                fragment.selection_set.as_ref()
+            }
+        };
+        if let Some(selection_set) = selection_set {
+            get_fragment_spreads(selection_set, &mut spreads);
+        }
+    }
+
+    for spread in spreads {
+        if !extracted_document.fragments.contains(&spread)
+            && !extracted_document.fragment_dependencies.contains(&spread)
+        {
+            extracted_document.fragment_dependencies.push(spread);
+        }
+    }
+
+    extracted_document.document = Some(document);
+    extracted_document
+}
+
+impl<'a> Visit for Extractor<'a> {
+    fn visit_import_decl(&mut self, node: &ImportDecl) {
+        self.active_gql_tag_identifiers
+            .extend(get_gql_tag_local_names(node, self.config));
+    }
+
+    fn visit_var_declarator(&mut self, node: &VarDeclarator) {
+        let is_gql_tag_init = node.init.as_ref().is_some_and(|init| {
+            init.as_tagged_tpl()
+                .is_some_and(|tag_tpl| self.is_gql_tag(&tag_tpl.tag))
+        });
+
+        if is_gql_tag_init {
+            self.pending_declarator = node.name.as_ident().map(|ident| ident.sym.to_string());
+        }
+        node.visit_children_with(self);
+        self.pending_declarator = None;
+    }
+
+    fn visit_tagged_tpl(&mut self, node: &TaggedTpl) {
+        if !self.is_gql_tag(&node.tag) || node.tpl.quasis.is_empty() {
+            node.visit_children_with(self);
+            return;
+        }
+
+        let declarator = self.pending_declarator.take();
+        self.documents.push(extract_document(node, declarator));
+    }
+}
+
+/// Returns every gql template of a program the transform would compile,
+/// without rewriting anything.
+pub fn extract_documents(program: &Program, config: &GraphQLTagConfig) -> Vec<ExtractedDocument> {
+    let mut extractor = Extractor {
+        config,
+        active_gql_tag_identifiers: vec![],
+        pending_declarator: None,
+        documents: vec![],
+    };
+    program.visit_with(&mut extractor);
+    extractor.documents
+}
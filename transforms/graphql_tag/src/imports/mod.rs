@@ -0,0 +1,397 @@
+// built-ins
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+// libs
+use once_cell::sync::Lazy;
+use regex::Regex;
+use swc_common::{BytePos, FileName, SourceFile};
+use swc_ecma_ast::*;
+use swc_ecma_parser::{parse_file_as_module, EsConfig, Syntax, TsConfig};
+
+// helpers
+use crate::{
+    parser::utils::strip_ignored_characters,
+    utils::{get_gql_tag_local_names, get_import_bindings},
+};
+
+// structs
+use crate::structs::{GraphQLError, GraphQLTagConfig, KnownDocument};
+
+const EXTENSIONS: [&str; 6] = [".ts", ".tsx", ".js", ".jsx", ".mjs", ".cjs"];
+
+static GRAPHQL_IMPORT_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?m)^[ \t]*#import[ \t]+["']([^"']+)["']"#).unwrap());
+
+enum Export {
+    Local(String),
+    Reexport(String, String),
+}
+
+struct ModuleInfo {
+    path: PathBuf,
+    gql_tag_identifiers: Vec<String>,
+    declarations: HashMap<String, Box<Expr>>,
+    imports: HashMap<String, (String, String)>,
+    exports: HashMap<String, Export>,
+}
+
+pub struct ImportResolver<'a> {
+    config: &'a GraphQLTagConfig,
+    cache: &'a mut HashMap<String, Option<KnownDocument>>,
+    stack: Vec<String>,
+}
+
+fn resolve_module_path(importer: &Path, specifier: &str) -> Option<PathBuf> {
+    let base = importer.parent()?.join(specifier);
+    if base.is_file() {
+        return Some(base);
+    }
+
+    for extension in EXTENSIONS {
+        let candidate = PathBuf::from(format!("{}{}", base.display(), extension));
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+    }
+
+    for extension in EXTENSIONS {
+        let candidate = base.join(format!("index{}", extension));
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+    }
+
+    None
+}
+
+fn get_syntax(path: &Path) -> Syntax {
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("ts") | Some("mts") | Some("cts") => Syntax::Typescript(TsConfig::default()),
+        Some("tsx") => Syntax::Typescript(TsConfig {
+            tsx: true,
+            ..Default::default()
+        }),
+        _ => Syntax::Es(EsConfig {
+            jsx: true,
+            ..Default::default()
+        }),
+    }
+}
+
+fn get_declared_name(declarator: &VarDeclarator) -> Option<String> {
+    declarator
+        .name
+        .as_ident()
+        .map(|ident| ident.sym.to_string())
+}
+
+fn get_module_export_name(name: &ModuleExportName) -> String {
+    match name {
+        ModuleExportName::Ident(ident) => ident.sym.to_string(),
+        ModuleExportName::Str(str) => str.value.to_string(),
+    }
+}
+
+impl ModuleInfo {
+    fn load(path: PathBuf, config: &GraphQLTagConfig) -> Option<Self> {
+        let source = fs::read_to_string(&path).ok()?;
+        let source_file = SourceFile::new(
+            FileName::Real(path.clone()),
+            false,
+            FileName::Real(path.clone()),
+            source,
+            BytePos(1),
+        );
+        let module = parse_file_as_module(
+            &source_file,
+            get_syntax(&path),
+            Default::default(),
+            None,
+            &mut vec![],
+        )
+        .ok()?;
+
+        let mut info = ModuleInfo {
+            path,
+            gql_tag_identifiers: vec![],
+            declarations: HashMap::new(),
+            imports: HashMap::new(),
+            exports: HashMap::new(),
+        };
+
+        for item in module.body {
+            match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) => {
+                    info.gql_tag_identifiers
+                        .extend(get_gql_tag_local_names(&import_decl, config));
+
+                    let source = import_decl.src.value.to_string();
+                    for (local, imported) in get_import_bindings(&import_decl) {
+                        info.imports.insert(local, (source.clone(), imported));
+                    }
+                }
+                ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => {
+                    info.add_declarations(var_decl.decls, false);
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::Var(var_decl),
+                    ..
+                })) => {
+                    info.add_declarations(var_decl.decls, true);
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named_export)) => {
+                    for specifier in named_export.specifiers {
+                        let ExportSpecifier::Named(specifier) = specifier else {
+                            continue;
+                        };
+                        let local = get_module_export_name(&specifier.orig);
+                        let exported = specifier
+                            .exported
+                            .as_ref()
+                            .map(get_module_export_name)
+                            .unwrap_or_else(|| local.clone());
+
+                        let export = match &named_export.src {
+                            Some(src) => Export::Reexport(src.value.to_string(), local),
+                            None => Export::Local(local),
+                        };
+                        info.exports.insert(exported, export);
+                    }
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export_default)) => {
+                    match export_default.expr.as_ident() {
+                        Some(ident) => {
+                            info.exports
+                                .insert("default".into(), Export::Local(ident.sym.to_string()));
+                        }
+                        None => {
+                            info.declarations
+                                .insert("*default*".into(), export_default.expr);
+                            info.exports
+                                .insert("default".into(), Export::Local("*default*".into()));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Some(info)
+    }
+
+    fn add_declarations(&mut self, declarators: Vec<VarDeclarator>, exported: bool) {
+        for declarator in declarators {
+            let (Some(name), Some(init)) = (get_declared_name(&declarator), declarator.init) else {
+                continue;
+            };
+
+            if exported {
+                self.exports
+                    .insert(name.clone(), Export::Local(name.clone()));
+            }
+            self.declarations.insert(name, init);
+        }
+    }
+}
+
+impl<'a> ImportResolver<'a> {
+    pub fn new(
+        config: &'a GraphQLTagConfig,
+        cache: &'a mut HashMap<String, Option<KnownDocument>>,
+    ) -> Self {
+        Self {
+            config,
+            cache,
+            stack: vec![],
+        }
+    }
+
+    pub fn resolve(
+        &mut self,
+        importer: &Path,
+        specifier: &str,
+        export_name: &str,
+    ) -> Option<KnownDocument> {
+        if !specifier.starts_with('.') {
+            return None;
+        }
+
+        let path = resolve_module_path(importer, specifier)?;
+        let key = format!("{}#{}", path.display(), export_name);
+
+        if let Some(cached) = self.cache.get(&key) {
+            return cached.clone();
+        }
+
+        // circular imports can not be resolved statically
+        if self.stack.contains(&key) {
+            return None;
+        }
+
+        self.stack.push(key.clone());
+        let resolved = ModuleInfo::load(path, self.config)
+            .and_then(|module| self.resolve_export(&module, export_name));
+        self.stack.pop();
+
+        self.cache.insert(key, resolved.clone());
+        resolved
+    }
+
+    fn resolve_export(&mut self, module: &ModuleInfo, export_name: &str) -> Option<KnownDocument> {
+        match module.exports.get(export_name)? {
+            Export::Local(local) => self.resolve_local(module, local),
+            Export::Reexport(specifier, name) => self.resolve(&module.path, specifier, name),
+        }
+    }
+
+    fn resolve_local(&mut self, module: &ModuleInfo, local: &str) -> Option<KnownDocument> {
+        if let Some((specifier, imported)) = module.imports.get(local) {
+            return self.resolve(&module.path, specifier, imported);
+        }
+
+        let key = format!("{}#local:{}", module.path.display(), local);
+        if self.stack.contains(&key) {
+            return None;
+        }
+
+        self.stack.push(key);
+        let resolved = self.resolve_declaration(module, local);
+        self.stack.pop();
+
+        resolved
+    }
+
+    fn resolve_declaration(&mut self, module: &ModuleInfo, local: &str) -> Option<KnownDocument> {
+        let tag_tpl = module.declarations.get(local)?.as_tagged_tpl()?;
+        let tag = tag_tpl.tag.as_ident()?;
+        if !module.gql_tag_identifiers.contains(&tag.sym.to_string()) {
+            return None;
+        }
+
+        let mut data: String = "".into();
+        for quasi in &tag_tpl.tpl.quasis {
+            data += quasi.cooked.as_ref().unwrap_or(&quasi.raw);
+        }
+
+        let gql_text = if self.config.strip {
+            strip_ignored_characters(data.clone()).unwrap_or(data)
+        } else {
+            data
+        };
+
+        let mut known_document = KnownDocument {
+            sources: vec![gql_text],
+            is_static: true,
+        };
+
+        for expression in &tag_tpl.tpl.exprs {
+            let resolved = expression
+                .as_ident()
+                .and_then(|ident| self.resolve_local(module, &ident.sym));
+
+            match resolved {
+                Some(resolved) => {
+                    known_document.is_static = known_document.is_static && resolved.is_static;
+                    for source in resolved.sources {
+                        if !known_document.sources.contains(&source) {
+                            known_document.sources.push(source);
+                        }
+                    }
+                }
+                None => known_document.is_static = false,
+            }
+        }
+
+        Some(known_document)
+    }
+}
+
+/// Reads the `.graphql` files referenced by `#import "./file.graphql"`
+/// comments of `body`, as graphql-tag/loader does, returning their sources
+/// and the sources they import themselves.
+pub fn resolve_graphql_imports(
+    importer: &Path,
+    body: &str,
+    strip: bool,
+) -> Result<Vec<String>, Vec<GraphQLError>> {
+    let mut sources = vec![];
+    let mut errors = vec![];
+    let mut visited = vec![];
+
+    collect_graphql_imports(
+        importer,
+        body,
+        strip,
+        None,
+        &mut sources,
+        &mut errors,
+        &mut visited,
+    );
+
+    if errors.is_empty() {
+        Ok(sources)
+    } else {
+        Err(errors)
+    }
+}
+
+fn collect_graphql_imports(
+    importer: &Path,
+    body: &str,
+    strip: bool,
+    root_import: Option<(usize, usize)>,
+    sources: &mut Vec<String>,
+    errors: &mut Vec<GraphQLError>,
+    visited: &mut Vec<PathBuf>,
+) {
+    for cap in GRAPHQL_IMPORT_RE.captures_iter(body) {
+        let specifier = cap.get(1).unwrap();
+        // errors in imported files point at the import of the root document
+        let (index, len) = root_import.unwrap_or((specifier.start(), specifier.as_str().len()));
+
+        let Some(path) = importer
+            .parent()
+            .map(|dir| dir.join(specifier.as_str()))
+            .filter(|path| path.is_file())
+        else {
+            errors.push(GraphQLError {
+                message: format!("Could not find imported file \"{}\"", specifier.as_str()),
+                index,
+                len,
+            });
+            continue;
+        };
+
+        if visited.contains(&path) {
+            continue;
+        }
+        visited.push(path.clone());
+
+        let Ok(source) = fs::read_to_string(&path) else {
+            continue;
+        };
+
+        collect_graphql_imports(
+            &path,
+            &source,
+            strip,
+            Some((index, len)),
+            sources,
+            errors,
+            visited,
+        );
+
+        let source = if strip {
+            strip_ignored_characters(source.clone()).unwrap_or(source)
+        } else {
+            source
+        };
+        if !sources.contains(&source) {
+            sources.push(source);
+        }
+    }
+}
@@ -1,44 +1,601 @@
 // built-ins
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
 
 // libs
-use miette::NamedSource;
-use swc_common::comments::Comments;
+use swc_common::{comments::Comments, errors::HANDLER, Span, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{VisitMut, VisitMutWith};
 
 // modules
+pub mod ast;
+mod builder;
+pub mod extract;
+pub mod imports;
+pub mod loader;
 pub mod parser;
 pub mod structs;
+pub mod transform;
+pub mod typegen;
 mod utils;
+pub mod validation;
+
+// re-exports
+pub use builder::{transform, GraphQLTagFolder, GraphQLTagOptions, TransformReport};
 
 // helpers
-use parser::utils::strip_ignored_characters;
+use ast::{lower_document, merge_documents, print_document, split_operations};
+use imports::{resolve_graphql_imports, ImportResolver};
+use loader::{
+    create_default_export, create_named_export, get_graphql_module_source, is_graphql_file,
+};
+use parser::urql::get_document_key;
+use parser::utils::{strip_ignored_characters, strip_template_quasis};
+use transform::{DocumentTransform, TransformContext, TransformRegistry};
+use typegen::{create_typed_document_node, generate_operation_types, is_typescript_file};
 
 // structs
-use structs::{GraphQLTagConfig, PrettyError, TransformVisitor};
-use utils::add_unique_fn_to_program;
+use structs::{
+    CommentTemplates, DocumentOptions, GraphQLError, GraphQLTagConfig, KnownDocument, OutputFormat,
+    OutputProfile, TemplateSource, TransformVisitor,
+};
+use typegen::schema::Schema;
+use utils::{
+    add_unique_fn_to_program, get_gql_tag_local_names, get_import_bindings, get_source_span,
+    get_template_source,
+};
+use validation::{get_name_pattern, validate_document, validate_fragment_spreads};
 
 impl<C> TransformVisitor<C>
 where
     C: Comments,
 {
     pub fn new(config: GraphQLTagConfig, comments: C) -> Self {
-        let _ = miette::set_hook(Box::new(|_| {
-            Box::new(
-                miette::MietteHandlerOpts::new()
-                    .force_graphical(true)
-                    .color(true)
-                    .build(),
-            )
-        }));
+        // the transforms behind boolean options run before the configured ones
+        let registry = TransformRegistry::default();
+        let options = [
+            ("pruneUnusedFragments", config.prune_unused_fragments),
+            ("inlineFragments", config.inline_fragments),
+            ("normalize", config.normalize),
+        ];
+        let mut transforms: Vec<Box<dyn DocumentTransform>> = options
+            .into_iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(name, _)| registry.create(name).unwrap())
+            .collect();
+        transforms.extend(config.transforms.iter().map(|factory| factory()));
+        // create_config rejects patterns that don't compile
+        let operation_name_pattern = config
+            .lint
+            .operation_name_pattern
+            .as_deref()
+            .and_then(|pattern| get_name_pattern(pattern).ok());
 
         Self {
+            unique_fn_name: "unique".into(),
             unique_fn_used: false,
-            active_gql_tag_identifiers: vec![],
+            active_gql_tag_identifiers: HashMap::new(),
             expr_def_map: HashMap::new(),
+            pending_declarator: None,
+            imported_bindings: HashMap::new(),
+            imported_documents: HashMap::new(),
             config,
             comments,
+            transforms,
+            schema: None,
+            operation_name_pattern,
+            operation_names: HashSet::new(),
+            documents: vec![],
+            error_count: 0,
+        }
+    }
+
+    /// Loads the schema types of TypeScript files are generated from.
+    fn load_schema(&mut self) {
+        let Some(schema_path) = &self.config.schema else {
+            return;
+        };
+        if self.schema.is_some() || !is_typescript_file(&self.config.file_path) {
+            return;
+        }
+
+        match Schema::load(schema_path) {
+            Ok(schema) => self.schema = Some(schema),
+            Err(error) => self.report_error(&error, DUMMY_SP),
+        }
+    }
+
+    fn resolve_identifier(&mut self, name: &str) -> Option<KnownDocument> {
+        if let Some(known_document) = self.expr_def_map.get(name) {
+            return Some(known_document.clone());
+        }
+
+        let (specifier, imported) = self.imported_bindings.get(name)?;
+        ImportResolver::new(&self.config, &mut self.imported_documents).resolve(
+            Path::new(&self.config.file_path),
+            specifier,
+            imported,
+        )
+    }
+
+    fn resolve_expressions(&mut self, expressions: &[Box<Expr>]) -> (Vec<String>, bool) {
+        let mut sources: Vec<String> = vec![];
+        let mut is_static = true;
+
+        for expression in expressions {
+            let known_document = expression
+                .as_ident()
+                .and_then(|ident| self.resolve_identifier(&ident.sym));
+
+            match known_document {
+                Some(known_document) => {
+                    is_static = is_static && known_document.is_static;
+                    for source in known_document.sources {
+                        if !sources.contains(&source) {
+                            sources.push(source);
+                        }
+                    }
+                }
+                None => is_static = false,
+            }
+        }
+
+        (sources, is_static)
+    }
+
+    /// Replaces a virtual module wrapping a `.graphql` file with one exporting
+    /// the compiled document as default, and each named operation by name,
+    /// like graphql-tag/loader.
+    fn transform_graphql_module(&mut self, module: &mut Module) {
+        let Some(source) = get_graphql_module_source(module) else {
+            return;
+        };
+
+        let Some((document_expr, document, _)) =
+            self.compile_gql_template(&source, &[], module.span, OutputProfile::Document)
+        else {
+            return;
+        };
+
+        let mut body = vec![create_default_export(document_expr, module.span)];
+        for (name, operation_document) in split_operations(&document) {
+            match self.print_document_text(&operation_document) {
+                Ok(operation_text) => {
+                    let operation_expr =
+                        self.create_static_document(&operation_document, operation_text);
+                    body.push(create_named_export(name, operation_expr, module.span));
+                }
+                Err(error) => self.report_error(&error, module.span),
+            }
+        }
+
+        module.body = body;
+    }
+
+    /// Whether an untagged template is marked as GraphQL with a leading
+    /// `/* GraphQL */` comment, and should be handled.
+    fn is_comment_template(&self, template: &Tpl) -> bool {
+        self.config.comment_templates != CommentTemplates::Ignore
+            && self
+                .comments
+                .get_leading(template.span.lo)
+                .is_some_and(|comments| {
+                    comments
+                        .iter()
+                        .any(|comment| comment.text.trim() == "GraphQL")
+                })
+    }
+
+    /// Returns the name of a gql template exported with a leading `@export`
+    /// comment, whose operations get exported individually.
+    fn get_annotated_export(&self, item: &ModuleItem) -> Option<String> {
+        let ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) = item else {
+            return None;
+        };
+        let Decl::Var(var_decl) = &export_decl.decl else {
+            return None;
+        };
+
+        let is_annotated = self
+            .comments
+            .get_leading(export_decl.span.lo)
+            .is_some_and(|comments| {
+                comments
+                    .iter()
+                    .any(|comment| comment.text.trim() == "@export")
+            });
+        if !is_annotated {
+            return None;
+        }
+
+        match var_decl.decls.as_slice() {
+            [declarator] => declarator
+                .name
+                .as_ident()
+                .map(|ident| ident.sym.to_string()),
+            _ => None,
+        }
+    }
+
+    /// Runs the configured transforms over `document`, returning the
+    /// transformed document when they changed it.
+    fn run_transforms(
+        &mut self,
+        original_document: &ast::Document,
+        is_static: bool,
+    ) -> Result<Option<ast::Document>, String> {
+        if self.transforms.is_empty() {
+            return Ok(None);
+        }
+
+        let mut transformed_document = original_document.clone();
+        let context = TransformContext {
+            file_path: &self.config.file_path,
+            is_static,
+        };
+        for transform in self.transforms.iter_mut() {
+            transform.transform(&mut transformed_document, &context)?;
+        }
+
+        if transformed_document != *original_document {
+            Ok(Some(transformed_document))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Prints a rewritten document the way the template text is emitted.
+    fn print_document_text(&self, document: &ast::Document) -> Result<String, String> {
+        let text = print_document(document);
+        if !self.config.strip {
+            return Ok(text);
+        }
+
+        strip_ignored_characters(text).map_err(|errors| {
+            errors
+                .first()
+                .map(|error| error.message().to_string())
+                .unwrap_or_default()
+        })
+    }
+
+    /// Precomputes the document apollo client sends to the server, `null`
+    /// when every selection is client-only. Documents without client-only
+    /// parts are sent as is and, like fragment-only documents, get none.
+    fn create_server_document(&mut self, document: &ast::Document) -> Result<Option<Expr>, String> {
+        let has_operation = document
+            .definitions
+            .iter()
+            .any(|definition| matches!(definition, ast::Definition::Operation(_)));
+        if !has_operation {
+            return Ok(None);
+        }
+
+        let mut server_document = document.clone();
+        let context = TransformContext {
+            file_path: &self.config.file_path,
+            is_static: true,
+        };
+        TransformRegistry::default()
+            .create("removeClientFields")
+            .unwrap()
+            .transform(&mut server_document, &context)?;
+        if server_document == *document {
+            return Ok(None);
+        }
+
+        let has_operation = server_document
+            .definitions
+            .iter()
+            .any(|definition| matches!(definition, ast::Definition::Operation(_)));
+        if !has_operation {
+            return Ok(Some(Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))));
+        }
+
+        let server_text = self.print_document_text(&server_document)?;
+        let options = DocumentOptions {
+            body: server_text,
+            span: DUMMY_SP,
+            expressions: &[],
+            unique_fn_name: &self.unique_fn_name,
+            output: OutputFormat::Object,
+            metadata: vec![],
+        };
+        Ok(Some(parser::create_graphql_document(
+            &server_document,
+            options,
+            &mut self.unique_fn_used,
+            &mut self.comments,
+        )))
+    }
+
+    fn create_static_document(&mut self, document: &ast::Document, gql_text: String) -> Expr {
+        let options = DocumentOptions {
+            body: gql_text,
+            span: DUMMY_SP,
+            expressions: &[],
+            unique_fn_name: &self.unique_fn_name,
+            output: self.config.output,
+            metadata: vec![],
+        };
+        parser::create_graphql_document(
+            document,
+            options,
+            &mut self.unique_fn_used,
+            &mut self.comments,
+        )
+    }
+
+    /// Compiles a template of a tag returning the GraphQL text itself into the
+    /// minified text, or an untagged template when it has interpolations,
+    /// which such tags concatenate as is.
+    fn compile_gql_string(&mut self, tag_tpl: &TaggedTpl) -> Option<Expr> {
+        // the result isn't a document other documents could interpolate
+        self.pending_declarator = None;
+
+        let mut template = (*tag_tpl.tpl).clone();
+        if !template.exprs.is_empty() {
+            strip_template_quasis(&mut template);
+            return Some(Expr::Tpl(template));
+        }
+
+        let source = get_template_source(&template.quasis);
+        let (_, _, gql_text) =
+            self.compile_gql_template(&source, &[], tag_tpl.span, OutputProfile::String)?;
+        let gql_text = strip_ignored_characters(gql_text.clone()).unwrap_or(gql_text);
+
+        Some(Expr::Lit(Lit::Str(Str {
+            span: tag_tpl.span,
+            value: gql_text.into(),
+            raw: None,
+        })))
+    }
+
+    /// Compiles the GraphQL text of a template, returning the document
+    /// expression along with the final document and GraphQL text it was
+    /// compiled from.
+    fn compile_gql_template(
+        &mut self,
+        source: &TemplateSource,
+        expressions: &[Box<Expr>],
+        span: Span,
+        profile: OutputProfile,
+    ) -> Option<(Expr, ast::Document, String)> {
+        let graphql_imports = if source.text.contains("#import") {
+            match resolve_graphql_imports(
+                Path::new(&self.config.file_path),
+                &source.text,
+                self.config.strip,
+            ) {
+                Ok(graphql_imports) => graphql_imports,
+                Err(errors) => {
+                    self.report_errors(source, span, errors);
+                    return None;
+                }
+            }
+        } else {
+            vec![]
+        };
+
+        // the text as written is checked, for diagnostics to point at it
+        let document = match parser::parse_graphql_tag(&source.text) {
+            Ok(document) => document,
+            // interpolations of names, fields or values only make a document
+            // at runtime, where graphql-tag parses the template
+            Err(errors) if !expressions.is_empty() => {
+                for error in errors {
+                    let warning_span =
+                        get_source_span(source, error.index, error.len).unwrap_or(span);
+                    self.report_warning(&error.message, warning_span);
+                }
+                return None;
+            }
+            Err(errors) => {
+                self.report_errors(source, span, errors);
+                return None;
+            }
+        };
+
+        let (interpolated_sources, is_static) = {
+            let (resolved_sources, is_static) = self.resolve_expressions(expressions);
+            let mut interpolated_sources = graphql_imports.clone();
+            for source in resolved_sources {
+                if !interpolated_sources.contains(&source) {
+                    interpolated_sources.push(source);
+                }
+            }
+            (interpolated_sources, is_static)
+        };
+
+        let validation_errors = validate_document(
+            &document,
+            &self.config,
+            self.operation_name_pattern.as_ref(),
+            &mut self.operation_names,
+            &interpolated_sources,
+        );
+        if !validation_errors.is_empty() {
+            self.report_errors(source, span, validation_errors);
+            return None;
+        }
+
+        // spreads can only be checked once every interpolation is known
+        if is_static {
+            for warning in validate_fragment_spreads(&document, &interpolated_sources) {
+                let warning_span =
+                    get_source_span(source, warning.index, warning.len).unwrap_or(span);
+                self.report_warning(&warning.message, warning_span);
+            }
+        }
+
+        let mut document = match lower_document(&document) {
+            Ok(document) => document,
+            Err(errors) => {
+                self.report_errors(source, span, errors);
+                return None;
+            }
+        };
+        let gql_text = if self.config.strip {
+            strip_ignored_characters(source.text.clone()).unwrap_or_else(|_| source.text.clone())
+        } else {
+            source.text.clone()
+        };
+
+        if let Some(declarator) = self.pending_declarator.take() {
+            let mut sources = vec![gql_text.clone()];
+            sources.extend(interpolated_sources.clone());
+            self.expr_def_map
+                .insert(declarator, KnownDocument { sources, is_static });
+        }
+
+        // `#import`ed files are always inlined, fully resolved cross
+        // file documents are inlined instead of concatenated at runtime,
+        // which the transforms below need to see every fragment of the document
+        let inline_interpolations = self.config.resolve_imports
+            || self.config.prune_unused_fragments
+            || self.config.inline_fragments
+            || self.config.server_document;
+        let (inlined_sources, expressions): (_, &[Box<Expr>]) =
+            if inline_interpolations && is_static && !expressions.is_empty() {
+                (interpolated_sources, &[])
+            } else {
+                (graphql_imports, expressions)
+            };
+
+        let mut is_merged = false;
+        if !inlined_sources.is_empty() {
+            let mut inlined_documents = vec![];
+            for inlined_source in &inlined_sources {
+                let inlined_document = parser::parse_graphql_tag(inlined_source)
+                    .and_then(|inlined_document| lower_document(&inlined_document));
+                match inlined_document {
+                    Ok(inlined_document) => inlined_documents.push(inlined_document),
+                    Err(errors) => {
+                        // interpolated documents aren't part of this template
+                        self.report_errors(&TemplateSource::default(), span, errors);
+                        return None;
+                    }
+                }
+            }
+
+            let definition_count = document.definitions.len();
+            merge_documents(&mut document, inlined_documents);
+            is_merged = document.definitions.len() != definition_count;
+        }
+
+        let (document, is_transformed) =
+            match self.run_transforms(&document, expressions.is_empty()) {
+                Ok(Some(transformed_document)) => (transformed_document, true),
+                Ok(None) => (document, false),
+                Err(error) => {
+                    self.report_error(&error, span);
+                    return None;
+                }
+            };
+
+        // rewritten documents are printed, others keep the text as written
+        let gql_text = if is_merged || is_transformed {
+            match self.print_document_text(&document) {
+                Ok(printed_text) => printed_text,
+                Err(error) => {
+                    self.report_error(&error, span);
+                    return None;
+                }
+            }
+        } else {
+            gql_text
+        };
+
+        let types = self.schema.as_ref().map(|schema| {
+            generate_operation_types(
+                &document,
+                schema,
+                &self.config.scalars,
+                !expressions.is_empty(),
+            )
+        });
+        let types = match types {
+            Some(Ok(types)) => types,
+            Some(Err(message)) => {
+                self.report_warning(&format!("Types were not generated: {}", message), span);
+                None
+            }
+            None => None,
+        };
+
+        let mut metadata = vec![];
+        if self.config.server_document && expressions.is_empty() {
+            match self.create_server_document(&document) {
+                Ok(Some(server_document)) => {
+                    metadata.push(("__serverDocument".into(), server_document))
+                }
+                Ok(None) => {}
+                Err(error) => {
+                    self.report_error(&error, span);
+                    return None;
+                }
+            }
+        }
+
+        // definitions interpolated at runtime change the key
+        if profile == OutputProfile::Urql && expressions.is_empty() {
+            let key = get_document_key(&document);
+            metadata.push((
+                "__key".into(),
+                Expr::Lit(Lit::Num(Number::from(key as f64))),
+            ));
+        }
+
+        let options = DocumentOptions {
+            body: gql_text.clone(),
+            span,
+            expressions,
+            unique_fn_name: &self.unique_fn_name,
+            output: self.config.output,
+            metadata,
+        };
+        let swc_ast = parser::create_graphql_document(
+            &document,
+            options,
+            &mut self.unique_fn_used,
+            &mut self.comments,
+        );
+
+        let swc_ast = match types {
+            Some(types) => create_typed_document_node(swc_ast, types, span),
+            None => swc_ast,
+        };
+
+        self.documents.push(gql_text.clone());
+
+        Some((swc_ast, document, gql_text))
+    }
+
+    /// Reports errors in the text of `source` where they are in the JS
+    /// source, or at `span` when they aren't in it.
+    fn report_errors(&mut self, source: &TemplateSource, span: Span, errors: Vec<GraphQLError>) {
+        for error in errors {
+            let error_span = get_source_span(source, error.index, error.len).unwrap_or(span);
+            self.report_error(&error.message, error_span);
+        }
+    }
+
+    fn report_error(&mut self, message: &str, span: Span) {
+        self.error_count += 1;
+        if HANDLER.is_set() {
+            HANDLER.with(|handler| handler.struct_span_err(span, message).emit());
+        } else {
+            eprintln!("GraphQL Error: {} in {}", message, self.config.file_path);
+        }
+    }
+
+    fn report_warning(&self, message: &str, span: Span) {
+        if HANDLER.is_set() {
+            HANDLER.with(|handler| handler.struct_span_warn(span, message).emit());
+        } else {
+            eprintln!("GraphQL Warning: {} in {}", message, self.config.file_path);
         }
     }
 }
@@ -48,137 +605,184 @@ where
     C: Comments,
 {
     fn visit_mut_program(&mut self, node: &mut Program) {
+        self.load_schema();
+
+        if is_graphql_file(&self.config.file_path) {
+            if let Program::Module(module) = node {
+                self.transform_graphql_module(module);
+                return;
+            }
+        }
+
         node.visit_mut_children_with(self);
         self.active_gql_tag_identifiers.clear();
 
         if self.unique_fn_used {
-            add_unique_fn_to_program(node, self.config.unique_fn_name.clone())
+            add_unique_fn_to_program(node, self.unique_fn_name.clone())
         }
     }
 
-    fn visit_mut_import_decl(&mut self, node: &mut ImportDecl) {
-        let mut gql_tag_local_name = None;
-        for import_specifier in &mut node.specifiers {
-            match import_specifier {
-                ImportSpecifier::Named(specifier) => {
-                    let local_name_string = specifier.local.sym.to_string();
-                    let mut import_name = local_name_string.clone();
-
-                    match &specifier.imported {
-                        Some(import_export_name) => match import_export_name {
-                            ModuleExportName::Ident(ident) => {
-                                import_name = ident.clone().sym.to_string()
-                            }
-                            ModuleExportName::Str(_) => {}
-                        },
-                        None => {}
-                    };
-
-                    if self.config.gql_tag_identifiers.contains(&import_name) {
-                        gql_tag_local_name = Some(local_name_string);
-                        break;
-                    }
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        let mut new_items = Vec::with_capacity(items.len());
 
+        for mut item in items.drain(..) {
+            let annotated_export = self.get_annotated_export(&item);
+            let item_span = item.span();
+            item.visit_mut_with(self);
+            new_items.push(item);
+
+            let Some(name) = annotated_export else {
+                continue;
+            };
+            let Some(known_document) = self.expr_def_map.get(&name) else {
+                continue;
+            };
+            if !known_document.is_static {
+                let message = format!(
+                    "Could not export operations of \"{}\", not every interpolation is known at compile time",
+                    name
+                );
+                self.report_warning(&message, item_span);
+                continue;
+            }
+
+            let documents = known_document
+                .sources
+                .iter()
+                .map(|source| {
+                    parser::parse_graphql_tag(source).and_then(|document| lower_document(&document))
+                })
+                .collect::<Result<Vec<_>, _>>();
+            let mut documents = match documents {
+                Ok(documents) => documents,
+                Err(errors) => {
+                    self.report_errors(&TemplateSource::default(), item_span, errors);
                     continue;
                 }
+            };
 
-                ImportSpecifier::Default(specifier) => {
-                    let local_name_string = specifier.local.sym.to_string();
-                    gql_tag_local_name = Some(local_name_string);
-                    break;
+            let mut document = documents.remove(0);
+            merge_documents(&mut document, documents);
+            for (operation_name, operation_document) in split_operations(&document) {
+                match self.print_document_text(&operation_document) {
+                    Ok(operation_text) => {
+                        let operation_expr =
+                            self.create_static_document(&operation_document, operation_text);
+                        new_items.push(create_named_export(
+                            operation_name,
+                            operation_expr,
+                            DUMMY_SP,
+                        ));
+                    }
+                    Err(error) => self.report_error(&error, item_span),
                 }
-
-                ImportSpecifier::Namespace(_) => break,
             }
         }
 
-        if gql_tag_local_name.is_none() {
+        *items = new_items;
+    }
+
+    fn visit_mut_var_declarator(&mut self, node: &mut VarDeclarator) {
+        let is_gql_tag_init = node.init.as_ref().is_some_and(|init| {
+            let is_gql_tag = init
+                .as_tagged_tpl()
+                .and_then(|tag_tpl| tag_tpl.tag.as_ident())
+                .is_some_and(|tag| {
+                    self.active_gql_tag_identifiers
+                        .contains_key(&tag.sym.to_string())
+                });
+            // compiled comment templates can be interpolated like gql ones
+            let is_compiled_comment_template = init.as_tpl().is_some_and(|template| {
+                self.config.comment_templates == CommentTemplates::Compile
+                    && template.exprs.is_empty()
+                    && self.is_comment_template(template)
+            });
+            is_gql_tag || is_compiled_comment_template
+        });
+
+        if !is_gql_tag_init {
+            node.visit_mut_children_with(self);
             return;
         }
 
-        let valid_import_source = self
-            .config
-            .import_sources
-            .contains(&node.src.value.to_string());
+        self.pending_declarator = node.name.as_ident().map(|ident| ident.sym.to_string());
+        node.visit_mut_children_with(self);
+        self.pending_declarator = None;
+    }
+
+    fn visit_mut_import_decl(&mut self, node: &mut ImportDecl) {
+        let source = node.src.value.to_string();
+        if self.config.resolve_imports && source.starts_with('.') {
+            for (local, imported) in get_import_bindings(node) {
+                self.imported_bindings
+                    .insert(local, (source.clone(), imported));
+            }
+        }
 
-        if valid_import_source {
+        let profile = self
+            .config
+            .output_profiles
+            .get(&source)
+            .copied()
+            .unwrap_or_default();
+        for gql_tag_local_name in get_gql_tag_local_names(node, &self.config) {
             self.active_gql_tag_identifiers
-                .push(gql_tag_local_name.unwrap());
+                .insert(gql_tag_local_name, profile);
         }
     }
 
     fn visit_mut_expr(&mut self, node: &mut Expr) {
         if let Some(tag_tpl) = node.as_mut_tagged_tpl() {
             if let Some(tag) = tag_tpl.tag.as_mut_ident() {
-                if !self
+                let Some(profile) = self
                     .active_gql_tag_identifiers
-                    .contains(&tag.sym.to_string())
-                {
+                    .get(&tag.sym.to_string())
+                    .copied()
+                else {
                     return;
-                }
+                };
 
-                if tag_tpl.tpl.quasis.len() == 0 {
+                if tag_tpl.tpl.quasis.is_empty() {
                     return;
                 }
 
-                let template = &mut tag_tpl.tpl;
-                let expressions = template.exprs.clone();
-
-                let mut data: String = "".into();
-                for quasi in &mut template.quasis {
-                    data += &quasi.raw;
+                if profile == OutputProfile::String {
+                    if let Some(string_expr) = self.compile_gql_string(tag_tpl) {
+                        *node = string_expr;
+                    }
+                    return;
                 }
 
-                let gql_raw_string = data.to_string();
-
-                let gql_text = if self.config.strip {
-                    let strip_result = strip_ignored_characters(gql_raw_string.clone());
-                    match strip_result {
-                        Ok(gql_strip_text) => gql_strip_text,
-                        Err(errors) => {
-                            for error in errors {
-                                println!(
-                                    "GraphQL Error: At index {}, {} got \"{}\" instead\n",
-                                    error.index(),
-                                    error.message(),
-                                    error.data()
-                                )
-                            }
-                            gql_raw_string
-                        }
-                    }
-                } else {
-                    gql_raw_string
-                };
+                let source = get_template_source(&tag_tpl.tpl.quasis);
 
-                let unique_fn_name = self.config.unique_fn_name.clone();
-                let gql_swc_ast_result = parser::parse_graphql_tag(
-                    gql_text.clone(),
-                    tag_tpl.span,
-                    expressions,
-                    &mut self.expr_def_map,
-                    unique_fn_name,
-                    &mut self.unique_fn_used,
-                    &mut self.comments,
-                );
+                if let Some((swc_ast, _, _)) =
+                    self.compile_gql_template(&source, &tag_tpl.tpl.exprs, tag_tpl.span, profile)
+                {
+                    *node = swc_ast;
+                } else if self.config.strip {
+                    // left for graphql-tag to parse at runtime, so keep it small
+                    strip_template_quasis(&mut tag_tpl.tpl);
+                }
+            }
+        } else if let Some(template) = node.as_mut_tpl() {
+            if !self.is_comment_template(template) {
+                node.visit_mut_children_with(self);
+                return;
+            }
 
-                match gql_swc_ast_result {
-                    Ok(swc_ast) => *node = swc_ast,
-                    Err(gql_ast) => {
-                        for error in gql_ast.errors() {
-                            let miette_error = miette::Report::new(PrettyError {
-                                ty: format!("{}, got {} instead", error.message(), error.data()),
-                                src: NamedSource::new(
-                                    self.config.file_path.clone(),
-                                    gql_text.clone(),
-                                ),
-                                span: (error.index(), error.data().len()).into(),
-                            });
-                            println!("\n{:?}", miette_error);
-                        }
-                    }
+            if self.config.comment_templates == CommentTemplates::Compile
+                && template.exprs.is_empty()
+            {
+                let source = get_template_source(&template.quasis);
+                if let Some((swc_ast, _, _)) =
+                    self.compile_gql_template(&source, &[], template.span, OutputProfile::Document)
+                {
+                    *node = swc_ast;
+                    return;
                 }
             }
+
+            strip_template_quasis(template);
         } else {
             node.visit_mut_children_with(self)
         }
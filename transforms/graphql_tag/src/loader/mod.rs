@@ -0,0 +1,65 @@
+// libs
+use swc_common::{BytePos, Span};
+use swc_ecma_ast::*;
+
+// structs
+use crate::structs::TemplateSource;
+
+pub fn is_graphql_file(file_path: &str) -> bool {
+    file_path.ends_with(".graphql") || file_path.ends_with(".gql")
+}
+
+/// Returns the GraphQL text of a virtual module wrapping a `.graphql` file,
+/// like `export default "query { ... }"` as emitted by raw loaders.
+pub fn get_graphql_module_source(module: &Module) -> Option<TemplateSource> {
+    let [ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export_default))] =
+        module.body.as_slice()
+    else {
+        return None;
+    };
+
+    match export_default.expr.as_ref() {
+        Expr::Lit(Lit::Str(str)) => Some(TemplateSource {
+            text: str.value.to_string(),
+            // inside the quotes
+            parts: vec![(
+                0,
+                Span::new(str.span.lo + BytePos(1), str.span.hi, str.span.ctxt),
+            )],
+        }),
+        // cooked, so escapes mean the same as in string literals
+        Expr::Tpl(tpl) if tpl.exprs.is_empty() => {
+            let mut source = TemplateSource::default();
+            for quasi in &tpl.quasis {
+                source.parts.push((source.text.len(), quasi.span));
+                source.text += quasi.cooked.as_ref()?;
+            }
+            Some(source)
+        }
+        _ => None,
+    }
+}
+
+pub fn create_default_export(expr: Expr, span: Span) -> ModuleItem {
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+        span,
+        expr: Box::new(expr),
+    }))
+}
+
+pub fn create_named_export(name: String, expr: Expr, span: Span) -> ModuleItem {
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+        span,
+        decl: Decl::Var(Box::new(VarDecl {
+            span,
+            kind: VarDeclKind::Const,
+            declare: false,
+            decls: vec![VarDeclarator {
+                span,
+                name: Pat::Ident(Ident::new(name.into(), span).into()),
+                init: Some(Box::new(expr)),
+                definite: false,
+            }],
+        })),
+    }))
+}
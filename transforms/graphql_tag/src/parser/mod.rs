@@ -1,43 +1,45 @@
-// built-ins
-use std::collections::HashMap;
-
 // libs
-use apollo_parser::SyntaxTree;
-use swc_common::{comments::Comments, Span};
+use apollo_parser::cst;
+use swc_common::comments::Comments;
 use swc_ecma_ast::*;
 
 // modules
 mod nodes;
+pub mod urql;
 pub mod utils;
 
 // helpers
 use nodes::document::create_document;
 
-pub fn parse_graphql_tag<C: Comments>(
-    body: String,
-    span: Span,
-    expressions: Vec<Box<Expr>>,
-    expr_def_map: &mut HashMap<String, Expr>,
-    unique_fn_name: String,
-    unique_fn_used: &mut bool,
-    comments: &mut C,
-) -> Result<Expr, SyntaxTree> {
-    let parser = apollo_parser::Parser::new(&body);
+// structs
+use crate::{
+    ast::Document,
+    structs::{DocumentOptions, GraphQLError},
+};
+
+pub fn parse_graphql_tag(body: &str) -> Result<cst::Document, Vec<GraphQLError>> {
+    let parser = apollo_parser::Parser::new(body);
     let ast = parser.parse();
 
     if ast.errors().len() != 0 {
-        Err(ast)
+        Err(ast
+            .errors()
+            .map(|error| GraphQLError {
+                message: format!("{}, got {} instead", error.message(), error.data()),
+                index: error.index(),
+                len: error.data().len(),
+            })
+            .collect())
     } else {
-        let doc = ast.document();
-        Ok(create_document(
-            doc,
-            span,
-            body,
-            expressions,
-            expr_def_map,
-            unique_fn_name,
-            unique_fn_used,
-            comments,
-        ))
+        Ok(ast.document())
     }
 }
+
+pub fn create_graphql_document<C: Comments>(
+    document: &Document,
+    options: DocumentOptions,
+    unique_fn_used: &mut bool,
+    comments: &mut C,
+) -> Expr {
+    create_document(document, options, unique_fn_used, comments)
+}
@@ -1,5 +1,4 @@
 // libs
-use apollo_parser::cst::{Argument, Arguments};
 use swc_common::Span;
 use swc_ecma_ast::*;
 
@@ -9,33 +8,24 @@ use crate::parser::{
     utils::get_key_value_node,
 };
 
-pub fn create_arguments(arguments: Option<Arguments>, span: Span) -> Expr {
-    if arguments.is_none() {
-        let args = ArrayLit {
-            span,
-            elems: vec![],
-        };
-        return Expr::Array(args);
-    }
+// structs
+use crate::ast::Argument;
 
-    let unwrapped_arguments = arguments.unwrap().arguments();
+pub fn create_arguments(arguments: &[Argument], span: Span) -> Expr {
     let mut all_arguments = vec![];
-    for argument in unwrapped_arguments {
+    for argument in arguments {
         all_arguments.push(create_argument(argument, span));
     }
-    return Expr::Array(ArrayLit {
+    Expr::Array(ArrayLit {
         span,
         elems: all_arguments,
-    });
+    })
 }
 
-fn create_argument(argument: Argument, span: Span) -> Option<ExprOrSpread> {
+fn create_argument(argument: &Argument, span: Span) -> Option<ExprOrSpread> {
     let kind = get_key_value_node("kind".into(), "Argument".into());
-    let name = get_key_value_node(
-        "name".into(),
-        create_name(argument.name().unwrap().text().as_str().into(), span),
-    );
-    let value = get_key_value_node("value".into(), create_value(argument.value(), span));
+    let name = get_key_value_node("name".into(), create_name(argument.name.clone(), span));
+    let value = get_key_value_node("value".into(), create_value(&argument.value, span));
     let arg = ObjectLit {
         span,
         props: vec![kind, name, value],
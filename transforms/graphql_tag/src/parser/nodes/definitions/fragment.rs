@@ -1,5 +1,4 @@
 // libs
-use apollo_parser::cst::FragmentDefinition;
 use swc_common::Span;
 use swc_ecma_ast::*;
 
@@ -12,26 +11,16 @@ use crate::parser::{
     utils::get_key_value_node,
 };
 
-pub fn create_fragment_definition(definition: FragmentDefinition, span: Span) -> Box<Expr> {
+// structs
+use crate::ast::FragmentDefinition;
+
+pub fn create_fragment_definition(definition: &FragmentDefinition, span: Span) -> Box<Expr> {
     let kind = get_key_value_node("kind".into(), "FragmentDefinition".into());
-    let name = get_key_value_node(
-        "name".into(),
-        create_name(
-            definition
-                .fragment_name()
-                .unwrap()
-                .name()
-                .unwrap()
-                .text()
-                .as_str()
-                .into(),
-            span,
-        ),
-    );
+    let name = get_key_value_node("name".into(), create_name(definition.name.clone(), span));
 
     let directives = get_key_value_node(
         "directives".into(),
-        create_directives(definition.directives(), span),
+        create_directives(&definition.directives, span),
     );
 
     let mut frag_def = ObjectLit {
@@ -39,19 +28,19 @@ pub fn create_fragment_definition(definition: FragmentDefinition, span: Span) ->
         props: vec![kind, name, directives],
     };
 
-    if definition.type_condition().is_some() {
+    if let Some(type_condition) = &definition.type_condition {
         let type_condition = get_key_value_node(
             "typeCondition".into(),
-            create_type_condition(definition.type_condition(), span),
+            create_type_condition(type_condition, span),
         );
 
         frag_def.props.push(type_condition);
     }
 
-    if definition.selection_set().is_some() {
+    if let Some(selection_set) = &definition.selection_set {
         let selection_set = get_key_value_node(
             "selectionSet".into(),
-            create_selection_set(definition.selection_set(), span),
+            create_selection_set(selection_set, span),
         );
 
         frag_def.props.push(selection_set);
@@ -1,5 +1,4 @@
 // libs
-use apollo_parser::cst::{CstChildren, Definition};
 use swc_common::Span;
 use swc_ecma_ast::*;
 
@@ -11,34 +10,13 @@ mod operation;
 use fragment::create_fragment_definition;
 use operation::create_operation_definition;
 
-pub fn create_definition(
-    definition: Definition,
-    span: Span,
-    assert_definition_name: bool,
-) -> Option<ExprOrSpread> {
-    if assert_definition_name {
-        definition.name().expect("GraphQL query must have name.");
-    }
+// structs
+use crate::ast::Definition;
+
+pub fn create_definition(definition: &Definition, span: Span) -> Option<ExprOrSpread> {
     let def_expr = match definition {
-        Definition::FragmentDefinition(frag_def) => create_fragment_definition(frag_def, span),
-        Definition::OperationDefinition(operation_def) => {
-            create_operation_definition(operation_def, span)
-        }
-        Definition::DirectiveDefinition(_) => todo!(),
-        Definition::SchemaDefinition(_) => todo!(),
-        Definition::ScalarTypeDefinition(_) => todo!(),
-        Definition::ObjectTypeDefinition(_) => todo!(),
-        Definition::InterfaceTypeDefinition(_) => todo!(),
-        Definition::UnionTypeDefinition(_) => todo!(),
-        Definition::EnumTypeDefinition(_) => todo!(),
-        Definition::InputObjectTypeDefinition(_) => todo!(),
-        Definition::SchemaExtension(_) => todo!(),
-        Definition::ScalarTypeExtension(_) => todo!(),
-        Definition::ObjectTypeExtension(_) => todo!(),
-        Definition::InterfaceTypeExtension(_) => todo!(),
-        Definition::UnionTypeExtension(_) => todo!(),
-        Definition::EnumTypeExtension(_) => todo!(),
-        Definition::InputObjectTypeExtension(_) => todo!(),
+        Definition::Fragment(frag_def) => create_fragment_definition(frag_def, span),
+        Definition::Operation(operation_def) => create_operation_definition(operation_def, span),
     };
 
     Some(ExprOrSpread {
@@ -47,12 +25,11 @@ pub fn create_definition(
     })
 }
 
-pub fn create_definitions(definitions: CstChildren<Definition>, span: Span) -> Expr {
+pub fn create_definitions(definitions: &[Definition], span: Span) -> Expr {
     let mut all_definitions = vec![];
-    let is_multiple_definitions = definitions.clone().count() > 1;
 
     for def in definitions {
-        all_definitions.push(create_definition(def, span, is_multiple_definitions));
+        all_definitions.push(create_definition(def, span));
     }
 
     Expr::Array(ArrayLit {
@@ -1,5 +1,4 @@
 // libs
-use apollo_parser::cst::OperationDefinition;
 use swc_common::Span;
 use swc_ecma_ast::*;
 
@@ -9,45 +8,42 @@ use crate::parser::{
         directive::create_directives, name::create_name, selection_set::create_selection_set,
         variables::create_variable_definitions,
     },
-    utils::{get_key_value_node, get_operation_token},
+    utils::get_key_value_node,
 };
 
-pub fn create_operation_definition(definition: OperationDefinition, span: Span) -> Box<Expr> {
+// structs
+use crate::ast::OperationDefinition;
+
+pub fn create_operation_definition(definition: &OperationDefinition, span: Span) -> Box<Expr> {
     let kind = get_key_value_node("kind".into(), "OperationDefinition".into());
 
     let variable_definitions = get_key_value_node(
         "variableDefinitions".into(),
-        create_variable_definitions(definition.variable_definitions(), span),
+        create_variable_definitions(&definition.variable_definitions, span),
     );
     let directives = get_key_value_node(
         "directives".into(),
-        create_directives(definition.directives(), span),
+        create_directives(&definition.directives, span),
     );
 
-    let operation = get_key_value_node(
-        "operation".into(),
-        get_operation_token(definition.operation_type()).into(),
-    );
+    let operation = get_key_value_node("operation".into(), definition.operation.as_str().into());
 
     let mut opr_def = ObjectLit {
         span,
         props: vec![kind, directives, variable_definitions, operation],
     };
 
-    if definition.name().is_some() {
+    if let Some(name) = &definition.name {
         opr_def.props.insert(
             1,
-            get_key_value_node(
-                "name".into(),
-                create_name(definition.name().unwrap().text().as_str().into(), span),
-            ),
+            get_key_value_node("name".into(), create_name(name.clone(), span)),
         );
     }
 
-    if definition.selection_set().is_some() {
+    if let Some(selection_set) = &definition.selection_set {
         let selection_set = get_key_value_node(
             "selectionSet".into(),
-            create_selection_set(definition.selection_set(), span),
+            create_selection_set(selection_set, span),
         );
 
         opr_def.props.push(selection_set);
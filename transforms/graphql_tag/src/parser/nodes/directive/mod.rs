@@ -1,5 +1,4 @@
 // libs
-use apollo_parser::cst::{Directive, Directives};
 use swc_common::Span;
 use swc_ecma_ast::*;
 
@@ -9,23 +8,21 @@ use crate::parser::{
     utils::get_key_value_node,
 };
 
-fn create_directive(directive: Directive, span: Span) -> Option<ExprOrSpread> {
+// structs
+use crate::ast::Directive;
+
+fn create_directive(directive: &Directive, span: Span) -> Option<ExprOrSpread> {
     let kind = get_key_value_node("kind".into(), "Directive".into());
-    let name = get_key_value_node(
-        "name".into(),
-        create_name(directive.name().unwrap().text().as_str().into(), span),
-    );
+    let name = get_key_value_node("name".into(), create_name(directive.name.clone(), span));
 
     let mut directive_object = ObjectLit {
         span,
         props: vec![kind, name],
     };
 
-    if directive.arguments().is_some() {
-        let arguments_prop = get_key_value_node(
-            "arguments".into(),
-            create_arguments(directive.arguments(), span),
-        );
+    if let Some(arguments) = &directive.arguments {
+        let arguments_prop =
+            get_key_value_node("arguments".into(), create_arguments(arguments, span));
 
         directive_object.props.push(arguments_prop)
     }
@@ -36,20 +33,11 @@ fn create_directive(directive: Directive, span: Span) -> Option<ExprOrSpread> {
     })
 }
 
-pub fn create_directives(directives: Option<Directives>, span: Span) -> Expr {
-    if directives.is_none() {
-        return Expr::Array(ArrayLit {
-            span,
-            elems: vec![],
-        });
-    }
-
+pub fn create_directives(directives: &[Directive], span: Span) -> Expr {
     Expr::Array(ArrayLit {
         span,
         elems: directives
-            .unwrap()
-            .directives()
-            .into_iter()
+            .iter()
             .map(|directive| create_directive(directive, span))
             .collect(),
     })
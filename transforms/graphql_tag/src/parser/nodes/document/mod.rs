@@ -1,13 +1,18 @@
-// built-ins
-use std::collections::HashMap;
-
 // libs
-use apollo_parser::cst::Document;
 use swc_common::{comments::Comments, BytePos, Span};
 use swc_ecma_ast::*;
 
 // helpers
-use crate::parser::{nodes::definitions::create_definitions, utils::get_key_value_node};
+use crate::parser::{
+    nodes::definitions::create_definitions,
+    utils::{expr_to_json, get_json_parse_call, get_key_value_node},
+};
+
+// structs
+use crate::{
+    ast::Document,
+    structs::{DocumentOptions, OutputFormat},
+};
 
 fn create_loc(body: String, span: Span) -> Expr {
     let start = get_key_value_node("start".into(), Expr::Lit(Lit::Num(Number::from(0))));
@@ -26,21 +31,29 @@ fn create_loc(body: String, span: Span) -> Expr {
 }
 
 pub fn create_document<C: Comments>(
-    document: Document,
-    span: Span,
-    body: String,
-    expressions: Vec<Box<Expr>>,
-    _expr_def_map: &mut HashMap<String, Expr>,
-    unique_fn_name: String,
+    document: &Document,
+    options: DocumentOptions,
     unique_fn_used: &mut bool,
     comments: &mut C,
 ) -> Expr {
+    let DocumentOptions {
+        body,
+        span,
+        expressions,
+        unique_fn_name,
+        output,
+        metadata,
+    } = options;
     let kind = get_key_value_node("kind".into(), "Document".into());
-    let definitions_expr = create_definitions(document.definitions(), span);
+    let mut definitions_expr = create_definitions(&document.definitions, span);
+
+    if output == OutputFormat::Json && !expressions.is_empty() {
+        definitions_expr = get_json_parse_call(expr_to_json(&definitions_expr), span);
+    }
 
     let mut all_expressions = vec![];
 
-    for _expression in expressions.clone() {
+    for _expression in expressions.iter().cloned() {
         let member_expr_for_definitions = MemberExpr {
             span,
             obj: _expression,
@@ -67,7 +80,7 @@ pub fn create_document<C: Comments>(
     let unique_fn_call_expr = Expr::Call(CallExpr {
         span: Span::with_lo(&span, span.lo() + BytePos(1)),
         callee: Callee::Expr(Box::new(Expr::Ident(Ident::new(
-            unique_fn_name.clone().into(),
+            unique_fn_name.into(),
             span,
         )))),
         args: vec![ExprOrSpread {
@@ -79,7 +92,7 @@ pub fn create_document<C: Comments>(
 
     let definitions = get_key_value_node(
         "definitions".into(),
-        if expressions.len() > 0 {
+        if !expressions.is_empty() {
             let mut unique_call_pos = unique_fn_call_expr.as_call().unwrap().span.lo();
             if unique_call_pos.is_dummy() {
                 unique_call_pos = Span::dummy_with_cmt().lo;
@@ -102,10 +115,29 @@ pub fn create_document<C: Comments>(
 
     let loc = get_key_value_node("loc".into(), create_loc(body, span));
 
-    let document_object_lit = ObjectLit {
-        span,
-        props: vec![kind, definitions, loc],
-    };
+    let mut props = vec![kind, definitions, loc];
+    for (key, value) in metadata {
+        props.push(get_key_value_node(key, value));
+    }
+
+    let document_object_lit = ObjectLit { span, props };
+
+    let document_expr = Expr::Object(document_object_lit);
+
+    if output == OutputFormat::Json && expressions.is_empty() {
+        let json_parse_call_expr = get_json_parse_call(
+            expr_to_json(&document_expr),
+            Span::with_lo(&span, span.lo() + BytePos(1)),
+        );
+
+        let mut json_parse_call_pos = json_parse_call_expr.as_call().unwrap().span.lo();
+        if json_parse_call_pos.is_dummy() {
+            json_parse_call_pos = Span::dummy_with_cmt().lo;
+        }
+        comments.add_pure_comment(json_parse_call_pos);
+
+        return json_parse_call_expr;
+    }
 
-    Expr::Object(document_object_lit)
+    document_expr
 }
@@ -1,5 +1,4 @@
 // libs
-use apollo_parser::cst::{CstChildren, Selection, SelectionSet};
 use swc_common::Span;
 use swc_ecma_ast::*;
 
@@ -10,19 +9,14 @@ mod selection;
 use crate::parser::utils::get_key_value_node;
 use selection::create_selection;
 
-pub fn create_selection_set(selection_set: Option<SelectionSet>, span: Span) -> Expr {
-    if selection_set.is_none() {
-        let sel_set = ObjectLit {
-            span,
-            props: vec![],
-        };
-        return Expr::Object(sel_set);
-    }
-    let unwrapped_selection_set = selection_set.unwrap();
+// structs
+use crate::ast::{Selection, SelectionSet};
+
+pub fn create_selection_set(selection_set: &SelectionSet, span: Span) -> Expr {
     let kind = get_key_value_node("kind".into(), "SelectionSet".into());
     let selections = get_key_value_node(
         "selections".into(),
-        create_selections(unwrapped_selection_set.selections(), span),
+        create_selections(&selection_set.selections, span),
     );
 
     let sel_set = ObjectLit {
@@ -32,7 +26,7 @@ pub fn create_selection_set(selection_set: Option<SelectionSet>, span: Span) ->
     Expr::Object(sel_set)
 }
 
-fn create_selections(selections: CstChildren<Selection>, span: Span) -> Expr {
+fn create_selections(selections: &[Selection], span: Span) -> Expr {
     let mut all_selections = vec![];
     for selection in selections {
         all_selections.push(create_selection(selection, span));
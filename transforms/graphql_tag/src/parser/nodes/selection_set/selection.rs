@@ -1,5 +1,4 @@
 // libs
-use apollo_parser::cst::{Field, FragmentSpread, InlineFragment, Selection};
 use swc_common::Span;
 use swc_ecma_ast::*;
 
@@ -13,7 +12,10 @@ use crate::parser::{
     utils::get_key_value_node,
 };
 
-pub fn create_selection(selection: Selection, span: Span) -> Option<ExprOrSpread> {
+// structs
+use crate::ast::{Field, FragmentSpread, InlineFragment, Selection};
+
+pub fn create_selection(selection: &Selection, span: Span) -> Option<ExprOrSpread> {
     match selection {
         Selection::Field(field) => create_field(field, span),
         Selection::FragmentSpread(frag_spread) => create_fragment_spread(frag_spread, span),
@@ -21,19 +23,14 @@ pub fn create_selection(selection: Selection, span: Span) -> Option<ExprOrSpread
     }
 }
 
-fn create_field(field: Field, span: Span) -> Option<ExprOrSpread> {
+fn create_field(field: &Field, span: Span) -> Option<ExprOrSpread> {
     let kind = get_key_value_node("kind".into(), "Field".into());
-    let name = get_key_value_node(
-        "name".into(),
-        create_name(field.name().unwrap().text().as_str().into(), span),
-    );
-    let arguments = get_key_value_node(
-        "arguments".into(),
-        create_arguments(field.arguments(), span),
-    );
+    let name = get_key_value_node("name".into(), create_name(field.name.clone(), span));
+    let arguments =
+        get_key_value_node("arguments".into(), create_arguments(&field.arguments, span));
     let directives = get_key_value_node(
         "directives".into(),
-        create_directives(field.directives(), span),
+        create_directives(&field.directives, span),
     );
 
     let mut sel: ObjectLit = ObjectLit {
@@ -41,20 +38,17 @@ fn create_field(field: Field, span: Span) -> Option<ExprOrSpread> {
         props: vec![kind, name, arguments, directives],
     };
 
-    if field.selection_set().is_some() {
+    if let Some(selection_set) = &field.selection_set {
         let sel_set = get_key_value_node(
             "selectionSet".into(),
-            create_selection_set(field.selection_set(), span),
+            create_selection_set(selection_set, span),
         );
 
         sel.props.push(sel_set);
     }
 
-    if field.alias().is_some() {
-        let alias = get_key_value_node(
-            "alias".into(),
-            create_name(field.alias().unwrap().name().unwrap().text().into(), span),
-        );
+    if let Some(alias) = &field.alias {
+        let alias = get_key_value_node("alias".into(), create_name(alias.clone(), span));
 
         sel.props.push(alias);
     }
@@ -65,25 +59,12 @@ fn create_field(field: Field, span: Span) -> Option<ExprOrSpread> {
     })
 }
 
-fn create_fragment_spread(frag_spread: FragmentSpread, span: Span) -> Option<ExprOrSpread> {
+fn create_fragment_spread(frag_spread: &FragmentSpread, span: Span) -> Option<ExprOrSpread> {
     let kind = get_key_value_node("kind".into(), "FragmentSpread".into());
-    let name = get_key_value_node(
-        "name".into(),
-        create_name(
-            frag_spread
-                .fragment_name()
-                .unwrap()
-                .name()
-                .unwrap()
-                .text()
-                .as_str()
-                .into(),
-            span,
-        ),
-    );
+    let name = get_key_value_node("name".into(), create_name(frag_spread.name.clone(), span));
     let directives = get_key_value_node(
         "directives".into(),
-        create_directives(frag_spread.directives(), span),
+        create_directives(&frag_spread.directives, span),
     );
     let fragment_spread = ObjectLit {
         span,
@@ -96,11 +77,11 @@ fn create_fragment_spread(frag_spread: FragmentSpread, span: Span) -> Option<Exp
     })
 }
 
-fn create_inline_fragment(inline_frag: InlineFragment, span: Span) -> Option<ExprOrSpread> {
+fn create_inline_fragment(inline_frag: &InlineFragment, span: Span) -> Option<ExprOrSpread> {
     let kind = get_key_value_node("kind".into(), "InlineFragment".into());
     let directives = get_key_value_node(
         "directives".into(),
-        create_directives(inline_frag.directives(), span),
+        create_directives(&inline_frag.directives, span),
     );
 
     let mut inline_frag_object = ObjectLit {
@@ -108,19 +89,19 @@ fn create_inline_fragment(inline_frag: InlineFragment, span: Span) -> Option<Exp
         props: vec![kind, directives],
     };
 
-    if inline_frag.type_condition().is_some() {
+    if let Some(type_condition) = &inline_frag.type_condition {
         let type_condition = get_key_value_node(
             "typeCondition".into(),
-            create_type_condition(inline_frag.type_condition(), span),
+            create_type_condition(type_condition, span),
         );
 
         inline_frag_object.props.push(type_condition);
     }
 
-    if inline_frag.selection_set().is_some() {
+    if let Some(selection_set) = &inline_frag.selection_set {
         let sel_set = get_key_value_node(
             "selectionSet".into(),
-            create_selection_set(inline_frag.selection_set(), span),
+            create_selection_set(selection_set, span),
         );
 
         inline_frag_object.props.push(sel_set);
@@ -1,9 +1,4 @@
 // libs
-use apollo_parser::cst::{
-    BooleanValue, CstChildren, EnumValue, FloatValue, IntValue, ListValue, NullValue, ObjectField,
-    ObjectValue, StringValue, Value,
-};
-use regex::Regex;
 use swc_common::Span;
 use swc_ecma_ast::*;
 
@@ -13,75 +8,52 @@ use crate::parser::{
     utils::get_key_value_node,
 };
 
-pub fn create_value(value: Option<Value>, span: Span) -> Expr {
-    assert!(value.is_some());
-    let unwrapped_value = value.unwrap();
-    match unwrapped_value {
+// structs
+use crate::ast::{ObjectField, Value};
+
+pub fn create_value(value: &Value, span: Span) -> Expr {
+    match value {
         Value::Variable(var) => create_variable_value(var, span),
-        Value::StringValue(str) => create_string_value(str, span),
-        Value::FloatValue(float) => create_float_value(float, span),
-        Value::IntValue(int) => create_int_value(int, span),
-        Value::BooleanValue(bool) => create_boolean_value(bool, span),
-        Value::NullValue(null) => create_null_value(null, span),
-        Value::EnumValue(enum_val) => create_enum_value(enum_val, span),
-        Value::ListValue(list) => create_list_value(list, span),
-        Value::ObjectValue(object) => create_object_value(object, span),
+        Value::String { value, block } => create_string_value(value, *block, span),
+        Value::Float(float) => create_literal_value("FloatValue", float, span),
+        Value::Int(int) => create_literal_value("IntValue", int, span),
+        Value::Boolean(bool) => create_boolean_value(*bool, span),
+        Value::Null => create_null_value(span),
+        Value::Enum(enum_val) => create_literal_value("EnumValue", enum_val, span),
+        Value::List(list) => create_list_value(list, span),
+        Value::Object(object) => create_object_value(object, span),
     }
 }
 
-fn create_string_value(str: StringValue, span: Span) -> Expr {
-    let kind = get_key_value_node("kind".into(), "StringValue".into());
+fn create_literal_value(kind: &str, value: &str, span: Span) -> Expr {
+    let kind = get_key_value_node("kind".into(), kind.into());
+    let value = get_key_value_node("value".into(), value.into());
 
-    let mut string_token: String = str.into();
-    let re = Regex::new(r#""(?P<str>[^"]*)""#).unwrap();
-    for cap in re.captures_iter(string_token.clone().as_str()) {
-        string_token = cap[0].to_string();
-    }
-    let value = get_key_value_node("value".into(), string_token.into());
-
-    let str_value = ObjectLit {
+    let literal_val = ObjectLit {
         span,
         props: vec![kind, value],
     };
 
-    Expr::Object(str_value)
+    Expr::Object(literal_val)
 }
 
-fn create_float_value(float: FloatValue, span: Span) -> Expr {
-    let kind = get_key_value_node("kind".into(), "FloatValue".into());
-    let value = get_key_value_node("value".into(), float.float_token().unwrap().text().into());
-
-    let float_val = ObjectLit {
-        span,
-        props: vec![kind, value],
-    };
-
-    Expr::Object(float_val)
-}
-
-fn create_int_value(int: IntValue, span: Span) -> Expr {
-    let kind = get_key_value_node("kind".into(), "IntValue".into());
-    let value = get_key_value_node("value".into(), int.int_token().unwrap().text().into());
-
-    let int_val = ObjectLit {
-        span,
-        props: vec![kind, value],
-    };
+fn create_string_value(value: &str, block: bool, span: Span) -> Expr {
+    let kind = get_key_value_node("kind".into(), "StringValue".into());
+    let value = get_key_value_node("value".into(), value.into());
+    let mut props = vec![kind, value];
+    if block {
+        props.push(get_key_value_node(
+            "block".into(),
+            Expr::Lit(Lit::Bool(true.into())),
+        ));
+    }
 
-    Expr::Object(int_val)
+    Expr::Object(ObjectLit { span, props })
 }
 
-fn create_boolean_value(bool: BooleanValue, span: Span) -> Expr {
+fn create_boolean_value(bool: bool, span: Span) -> Expr {
     let kind = get_key_value_node("kind".into(), "BooleanValue".into());
-    let value = get_key_value_node(
-        "value".into(),
-        (|| {
-            if bool.true_token().is_some() {
-                return Expr::Lit(Lit::Bool(true.into()));
-            }
-            return Expr::Lit(Lit::Bool(false.into()));
-        })(),
-    );
+    let value = get_key_value_node("value".into(), Expr::Lit(Lit::Bool(bool.into())));
 
     let bool_val = ObjectLit {
         span,
@@ -91,19 +63,7 @@ fn create_boolean_value(bool: BooleanValue, span: Span) -> Expr {
     Expr::Object(bool_val)
 }
 
-fn create_enum_value(enum_val: EnumValue, span: Span) -> Expr {
-    let kind = get_key_value_node("kind".into(), "EnumValue".into());
-    let value = get_key_value_node("value".into(), enum_val.text().as_str().into());
-
-    let enum_val_obj = ObjectLit {
-        span,
-        props: vec![kind, value],
-    };
-
-    Expr::Object(enum_val_obj)
-}
-
-fn create_null_value(_null: NullValue, span: Span) -> Expr {
+fn create_null_value(span: Span) -> Expr {
     let kind = get_key_value_node("kind".into(), "NullValue".into());
 
     let null_val = ObjectLit {
@@ -114,12 +74,9 @@ fn create_null_value(_null: NullValue, span: Span) -> Expr {
     Expr::Object(null_val)
 }
 
-fn create_list_value(list: ListValue, span: Span) -> Expr {
+fn create_list_value(list: &[Value], span: Span) -> Expr {
     let kind = get_key_value_node("kind".into(), "ListValue".into());
-    let values = get_key_value_node(
-        "values".into(),
-        create_list_value_values(list.values(), span),
-    );
+    let values = get_key_value_node("values".into(), create_list_value_values(list, span));
 
     let list_val = ObjectLit {
         span,
@@ -129,12 +86,9 @@ fn create_list_value(list: ListValue, span: Span) -> Expr {
     Expr::Object(list_val)
 }
 
-fn create_object_value(object: ObjectValue, span: Span) -> Expr {
+fn create_object_value(object: &[ObjectField], span: Span) -> Expr {
     let kind = get_key_value_node("kind".into(), "ObjectValue".into());
-    let fields = get_key_value_node(
-        "fields".into(),
-        create_object_fields(object.object_fields(), span),
-    );
+    let fields = get_key_value_node("fields".into(), create_object_fields(object, span));
 
     let object_val = ObjectLit {
         span,
@@ -144,9 +98,9 @@ fn create_object_value(object: ObjectValue, span: Span) -> Expr {
     Expr::Object(object_val)
 }
 
-fn create_object_fields(object_fields: CstChildren<ObjectField>, span: Span) -> Expr {
+fn create_object_fields(object_fields: &[ObjectField], span: Span) -> Expr {
     let mut all_fields = vec![];
-    for field in object_fields.into_iter() {
+    for field in object_fields {
         all_fields.push(Some(ExprOrSpread {
             spread: None,
             expr: Box::new(create_object_field(field, span)),
@@ -159,13 +113,10 @@ fn create_object_fields(object_fields: CstChildren<ObjectField>, span: Span) ->
     })
 }
 
-fn create_object_field(field: ObjectField, span: Span) -> Expr {
+fn create_object_field(field: &ObjectField, span: Span) -> Expr {
     let kind = get_key_value_node("kind".into(), "ObjectField".into());
-    let name = get_key_value_node(
-        "name".into(),
-        create_name(field.name().unwrap().text().as_str().into(), span),
-    );
-    let value = get_key_value_node("value".into(), create_value(field.value(), span));
+    let name = get_key_value_node("name".into(), create_name(field.name.clone(), span));
+    let value = get_key_value_node("value".into(), create_value(&field.value, span));
 
     let object_field_value = ObjectLit {
         span,
@@ -175,12 +126,12 @@ fn create_object_field(field: ObjectField, span: Span) -> Expr {
     Expr::Object(object_field_value)
 }
 
-fn create_list_value_values(values: CstChildren<Value>, span: Span) -> Expr {
+fn create_list_value_values(values: &[Value], span: Span) -> Expr {
     let mut all_values = vec![];
-    for value in values.into_iter() {
+    for value in values {
         all_values.push(Some(ExprOrSpread {
             spread: None,
-            expr: Box::new(create_value(Some(value), span)),
+            expr: Box::new(create_value(value, span)),
         }))
     }
 
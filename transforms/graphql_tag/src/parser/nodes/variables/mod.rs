@@ -1,5 +1,4 @@
 // libs
-use apollo_parser::cst::{DefaultValue, Variable, VariableDefinition, VariableDefinitions};
 use swc_common::Span;
 use swc_ecma_ast::*;
 
@@ -12,16 +11,12 @@ use crate::parser::{
     utils::get_key_value_node,
 };
 
-pub fn create_variable_definitions(variable_defs: Option<VariableDefinitions>, span: Span) -> Expr {
-    if variable_defs.is_none() {
-        return Expr::Array(ArrayLit {
-            span,
-            elems: vec![],
-        });
-    }
+// structs
+use crate::ast::VariableDefinition;
 
+pub fn create_variable_definitions(variable_defs: &[VariableDefinition], span: Span) -> Expr {
     let mut all_variable_definitions = vec![];
-    for variable_def in variable_defs.unwrap().variable_definitions() {
+    for variable_def in variable_defs {
         all_variable_definitions.push(create_variable_definition(variable_def, span))
     }
 
@@ -32,17 +27,17 @@ pub fn create_variable_definitions(variable_defs: Option<VariableDefinitions>, s
 }
 
 fn create_variable_definition(
-    variable_def: VariableDefinition,
+    variable_def: &VariableDefinition,
     span: Span,
 ) -> Option<ExprOrSpread> {
     let kind = get_key_value_node("kind".into(), "VariableDefinition".into());
     let directives = get_key_value_node(
         "directives".into(),
-        create_directives(variable_def.directives(), span),
+        create_directives(&variable_def.directives, span),
     );
     let variable = get_key_value_node(
         "variable".into(),
-        create_variable_value(variable_def.variable().unwrap(), span),
+        create_variable_value(&variable_def.variable, span),
     );
 
     let mut var_def = ObjectLit {
@@ -50,16 +45,14 @@ fn create_variable_definition(
         props: vec![kind, directives, variable],
     };
 
-    if variable_def.ty().is_some() {
-        let type_def = get_key_value_node("type".into(), create_type_node(variable_def.ty(), span));
+    if let Some(ty) = &variable_def.ty {
+        let type_def = get_key_value_node("type".into(), create_type_node(ty, span));
         var_def.props.push(type_def);
     }
 
-    if variable_def.default_value().is_some() {
-        let default_value = get_key_value_node(
-            "defaultValue".into(),
-            create_default_value(variable_def.default_value(), span),
-        );
+    if let Some(default_value) = &variable_def.default_value {
+        let default_value =
+            get_key_value_node("defaultValue".into(), create_value(default_value, span));
         var_def.props.push(default_value);
     }
 
@@ -69,24 +62,9 @@ fn create_variable_definition(
     })
 }
 
-fn create_default_value(default_value: Option<DefaultValue>, span: Span) -> Expr {
-    if default_value.is_none() {
-        return Expr::Object(ObjectLit {
-            span,
-            props: vec![],
-        });
-    }
-
-    let unwrapped_default_value = default_value.unwrap();
-    create_value(unwrapped_default_value.value(), span)
-}
-
-pub fn create_variable_value(var: Variable, span: Span) -> Expr {
+pub fn create_variable_value(var: &str, span: Span) -> Expr {
     let kind = get_key_value_node("kind".into(), "Variable".into());
-    let name = get_key_value_node(
-        "name".into(),
-        create_name(var.name().unwrap().text().as_str().into(), span),
-    );
+    let name = get_key_value_node("name".into(), create_name(var.into(), span));
     let variable = ObjectLit {
         span,
         props: vec![kind, name],
@@ -0,0 +1,46 @@
+// structs
+use crate::ast::{print_document, Definition, Document};
+
+// urql's `phash`: djb2 over UTF-16 code units, where only the shifted
+// operand is truncated to 32 bits, so the key may grow past them
+fn phash(text: &str, seed: Option<i64>) -> i64 {
+    let mut hash = seed.map(|seed| seed as i32 as i64).unwrap_or(5381);
+    for code_unit in text.encode_utf16() {
+        hash = ((hash as i32).wrapping_shl(5) as i64) + hash + code_unit as i64;
+    }
+    hash
+}
+
+// urql's `sanitizeDocument` drops comments and collapses line breaks, and
+// printed documents have neither comments nor line breaks inside strings
+fn sanitize_document(text: &str) -> String {
+    let mut sanitized = String::with_capacity(text.len());
+    for char in text.chars() {
+        let is_line_break = char == '\n' || char == '\r';
+        if is_line_break && sanitized.ends_with('\n') {
+            continue;
+        }
+        sanitized.push(if is_line_break { '\n' } else { char });
+    }
+    sanitized.trim().to_string()
+}
+
+/// Returns the `__key` urql's `keyDocument` computes for `document`: the hash
+/// of its printed text, seeded into the hash of its operation name if any.
+pub fn get_document_key(document: &Document) -> i64 {
+    let key = phash(&sanitize_document(&print_document(document)), None);
+
+    let operation_name = document
+        .definitions
+        .iter()
+        .find_map(|definition| match definition {
+            Definition::Operation(operation) => Some(operation.name.as_deref()),
+            Definition::Fragment(_) => None,
+        })
+        .flatten();
+
+    match operation_name {
+        Some(operation_name) => phash(&format!("\n# {}", operation_name), Some(key)),
+        None => key,
+    }
+}
@@ -1,5 +1,7 @@
-use apollo_parser::{cst::OperationType, Error, Lexer, TokenKind};
+// libs
+use apollo_parser::{Error, Lexer, TokenKind};
 
+use swc_common::Span;
 use swc_ecma_ast::*;
 
 pub fn get_key_value_node(key: String, value: Expr) -> PropOrSpread {
@@ -9,48 +11,26 @@ pub fn get_key_value_node(key: String, value: Expr) -> PropOrSpread {
     })))
 }
 
-pub fn get_operation_token(operation_type: Option<OperationType>) -> String {
-    if operation_type.is_none() {
-        return "query".into();
-    }
-
-    let opr_token = operation_type.unwrap();
-
-    if opr_token.query_token().is_some() {
-        return opr_token.query_token().unwrap().text().into();
-    }
-
-    if opr_token.mutation_token().is_some() {
-        return opr_token.mutation_token().unwrap().text().into();
-    }
-
-    if opr_token.subscription_token().is_some() {
-        return opr_token.subscription_token().unwrap().text().into();
-    }
-
-    "query".into()
-}
-
 fn is_punctuator_token_kind(kind: TokenKind) -> bool {
-    match kind {
+    matches!(
+        kind,
         TokenKind::Bang
-        | TokenKind::Dollar
-        | TokenKind::Amp
-        | TokenKind::Spread
-        | TokenKind::Comma
-        | TokenKind::Colon
-        | TokenKind::Eq
-        | TokenKind::At
-        | TokenKind::LParen
-        | TokenKind::RParen
-        | TokenKind::LBracket
-        | TokenKind::RBracket
-        | TokenKind::LCurly
-        | TokenKind::RCurly
-        | TokenKind::Pipe
-        | TokenKind::Eof => true,
-        _ => false,
-    }
+            | TokenKind::Dollar
+            | TokenKind::Amp
+            | TokenKind::Spread
+            | TokenKind::Comma
+            | TokenKind::Colon
+            | TokenKind::Eq
+            | TokenKind::At
+            | TokenKind::LParen
+            | TokenKind::RParen
+            | TokenKind::LBracket
+            | TokenKind::RBracket
+            | TokenKind::LCurly
+            | TokenKind::RCurly
+            | TokenKind::Pipe
+            | TokenKind::Eof
+    )
 }
 
 pub fn strip_ignored_characters(source: String) -> Result<String, Vec<Error>> {
@@ -60,7 +40,7 @@ pub fn strip_ignored_characters(source: String) -> Result<String, Vec<Error>> {
     let mut was_last_added_token_non_punctuator = false;
     let (tokens, errors) = lexer.lex();
 
-    if errors.len() != 0 {
+    if !errors.is_empty() {
         return Err(errors);
     }
 
@@ -84,3 +64,98 @@ pub fn strip_ignored_characters(source: String) -> Result<String, Vec<Error>> {
 
     Ok(stripped_body)
 }
+
+/// Strips the quasis of a template that can't be compiled, keeping its
+/// interpolations separated from the text around them. Templates whose
+/// interpolations would get lost, like in comments, are left as is.
+pub fn strip_template_quasis(template: &mut Tpl) {
+    // a name token, so stripping keeps the spaces an interpolated name needs
+    const PLACEHOLDER: &str = "__graphql_tag_interpolation__";
+
+    // escapes would need the cooked quasis to be recomputed
+    if template
+        .quasis
+        .iter()
+        .any(|quasi| quasi.raw.contains('\\') || quasi.raw.contains(PLACEHOLDER))
+    {
+        return;
+    }
+
+    let source = template
+        .quasis
+        .iter()
+        .map(|quasi| quasi.raw.to_string())
+        .collect::<Vec<_>>()
+        .join(PLACEHOLDER);
+    let Ok(stripped_source) = strip_ignored_characters(source) else {
+        return;
+    };
+
+    let stripped_quasis: Vec<&str> = stripped_source.split(PLACEHOLDER).collect();
+    if stripped_quasis.len() != template.quasis.len() {
+        return;
+    }
+
+    for (quasi, stripped_quasi) in template.quasis.iter_mut().zip(stripped_quasis) {
+        quasi.raw = stripped_quasi.into();
+        quasi.cooked = Some(stripped_quasi.into());
+    }
+}
+
+pub fn expr_to_json(expr: &Expr) -> String {
+    match expr {
+        Expr::Object(object) => {
+            let props: Vec<String> = object
+                .props
+                .iter()
+                .map(|prop| match prop {
+                    PropOrSpread::Prop(prop) => match prop.as_ref() {
+                        Prop::KeyValue(KeyValueProp {
+                            key: PropName::Str(key),
+                            value,
+                        }) => format!(
+                            "{}:{}",
+                            serde_json::to_string(key.value.as_str()).unwrap(),
+                            expr_to_json(value)
+                        ),
+                        _ => unreachable!("document props are always string keyed"),
+                    },
+                    PropOrSpread::Spread(_) => unreachable!("documents never contain spreads"),
+                })
+                .collect();
+            format!("{{{}}}", props.join(","))
+        }
+        Expr::Array(array) => {
+            let elems: Vec<String> = array
+                .elems
+                .iter()
+                .map(|elem| match elem {
+                    Some(elem) => expr_to_json(&elem.expr),
+                    None => "null".into(),
+                })
+                .collect();
+            format!("[{}]", elems.join(","))
+        }
+        Expr::Lit(Lit::Str(str)) => serde_json::to_string(str.value.as_str()).unwrap(),
+        Expr::Lit(Lit::Num(num)) => num.value.to_string(),
+        Expr::Lit(Lit::Bool(bool)) => bool.value.to_string(),
+        Expr::Lit(Lit::Null(_)) => "null".into(),
+        _ => unreachable!("documents only contain JSON compatible literals"),
+    }
+}
+
+pub fn get_json_parse_call(json: String, span: Span) -> Expr {
+    Expr::Call(CallExpr {
+        span,
+        callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+            span,
+            obj: Box::new(Expr::Ident(Ident::new("JSON".into(), span))),
+            prop: MemberProp::Ident(Ident::new("parse".into(), span)),
+        }))),
+        args: vec![ExprOrSpread {
+            spread: None,
+            expr: Box::new(Expr::Lit(Lit::Str(json.into()))),
+        }],
+        type_args: None,
+    })
+}
@@ -1,39 +1,211 @@
 // built-ins
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
-use swc_common::comments::Comments;
 // libs
-use miette::{Diagnostic, NamedSource, SourceSpan};
+use regex::Regex;
+use serde::Deserialize;
+use swc_common::{comments::Comments, Span};
 use swc_ecma_ast::Expr;
-use thiserror::Error;
 
+// structs
+use crate::{
+    transform::{DocumentTransform, TransformFactory},
+    typegen::schema::Schema,
+};
+
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum OutputFormat {
+    /// Emit documents as plain object literals.
+    #[default]
+    Object,
+    /// Emit documents as `JSON.parse("...")` calls, which V8 parses faster
+    /// than equivalent object literals once they grow past ~10KB.
+    Json,
+}
+
+/// What a gql tag returns, which depends on the library it's imported from.
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum OutputProfile {
+    /// A `DocumentNode`, like the `gql` of graphql-tag and Apollo Client.
+    #[default]
+    Document,
+    /// The minified GraphQL text, like the `gql` of graphql-request.
+    String,
+    /// A `DocumentNode` with the `__key` urql would compute for it at runtime.
+    Urql,
+}
+
+/// What to do with untagged templates marked with a leading `/* GraphQL */`
+/// comment, like the ones sent by graphql-request or fetch.
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum CommentTemplates {
+    #[default]
+    Ignore,
+    /// Remove ignored characters, keeping the template a string.
+    Strip,
+    /// Compile templates without interpolations into documents like gql
+    /// templates, and strip the others.
+    Compile,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct LintConfig {
+    /// Every operation must have a name.
+    pub require_operation_name: bool,
+    /// Regex (or `"PascalCase"` / `"camelCase"`) operation names must match.
+    pub operation_name_pattern: Option<String>,
+    /// Operation names must be unique within a module.
+    pub unique_operation_names: bool,
+    /// Fragment names must start with the name of their type condition.
+    pub fragment_name_matches_type: bool,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct LimitsConfig {
+    /// Maximum nesting of fields in an operation.
+    pub max_depth: Option<usize>,
+    /// Maximum number of aliased fields in an operation.
+    pub max_aliases: Option<usize>,
+    /// Maximum number of fields in an operation, weighted by `complexity`.
+    pub max_field_count: Option<usize>,
+    /// Weight of fields by name, fields not listed weigh 1.
+    pub complexity: HashMap<String, usize>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct DirectivesConfig {
+    /// Directives documents may use.
+    pub allow: Vec<String>,
+    /// Directives reported even when allowed.
+    pub deny: Vec<String>,
+}
+
+impl Default for DirectivesConfig {
+    fn default() -> Self {
+        Self {
+            allow: ["include", "skip", "defer", "stream", "client", "connection"]
+                .map(String::from)
+                .to_vec(),
+            deny: vec![],
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct GraphQLTagConfig {
     pub import_sources: Vec<String>,
     pub gql_tag_identifiers: Vec<String>,
     pub strip: bool,
+    pub output: OutputFormat,
+    pub schema: Option<String>,
+    pub scalars: HashMap<String, String>,
+    pub lint: LintConfig,
+    pub directives: Option<DirectivesConfig>,
+    pub limits: LimitsConfig,
+    pub transforms: Vec<TransformFactory>,
+    pub resolve_imports: bool,
+    pub prune_unused_fragments: bool,
+    pub inline_fragments: bool,
+    pub normalize: bool,
+    pub server_document: bool,
+    pub comment_templates: CommentTemplates,
+    pub output_profiles: HashMap<String, OutputProfile>,
     pub file_path: String,
-    pub unique_fn_name: String,
-    pub unique_fn_used: bool,
+}
+
+impl Default for GraphQLTagConfig {
+    fn default() -> Self {
+        Self {
+            import_sources: vec!["@apollo/client".to_string(), "graphql-tag".into()],
+            gql_tag_identifiers: vec!["gql".to_string()],
+            strip: false,
+            output: OutputFormat::Object,
+            schema: None,
+            scalars: HashMap::new(),
+            lint: LintConfig::default(),
+            directives: None,
+            limits: LimitsConfig::default(),
+            transforms: vec![],
+            resolve_imports: false,
+            prune_unused_fragments: false,
+            inline_fragments: false,
+            normalize: false,
+            server_document: false,
+            comment_templates: CommentTemplates::Ignore,
+            output_profiles: HashMap::new(),
+            file_path: String::new(),
+        }
+    }
 }
 
 pub struct TransformVisitor<C>
 where
     C: Comments,
 {
-    pub active_gql_tag_identifiers: Vec<String>,
-    pub expr_def_map: HashMap<String, Expr>,
+    // local names of gql tags, with the profile of the library they come from
+    pub active_gql_tag_identifiers: HashMap<String, OutputProfile>,
+    pub expr_def_map: HashMap<String, KnownDocument>,
+    pub pending_declarator: Option<String>,
+    pub imported_bindings: HashMap<String, (String, String)>,
+    pub imported_documents: HashMap<String, Option<KnownDocument>>,
     pub config: GraphQLTagConfig,
     pub comments: C,
+    // the boolean options' transforms, then the configured ones
+    pub transforms: Vec<Box<dyn DocumentTransform>>,
+    // name of the helper deduplicating definitions, added when used
+    pub unique_fn_name: String,
     pub unique_fn_used: bool,
+    pub schema: Option<Arc<Schema>>,
+    // `lint.operationNamePattern`, compiled once for every template
+    pub operation_name_pattern: Option<Regex>,
+    pub operation_names: HashSet<String>,
+    // compiled GraphQL text of every document, and how many errors were reported
+    pub documents: Vec<String>,
+    pub error_count: usize,
+}
+
+/// How a compiled document is emitted, besides its definitions.
+pub struct DocumentOptions<'a> {
+    /// GraphQL text exposed as `loc.source.body`.
+    pub body: String,
+    pub span: Span,
+    /// Documents interpolated at runtime, whose definitions get concatenated.
+    pub expressions: &'a [Box<Expr>],
+    /// Name of the helper deduplicating concatenated definitions.
+    pub unique_fn_name: &'a str,
+    pub output: OutputFormat,
+    /// Extra properties of the document object.
+    pub metadata: Vec<(String, Expr)>,
+}
+
+/// GraphQL text read from the JS source, with the offset in the text and
+/// the span of each of its parts, to point diagnostics at the JS source.
+#[derive(Clone, Debug, Default)]
+pub struct TemplateSource {
+    pub text: String,
+    pub parts: Vec<(usize, Span)>,
+}
+
+/// A compiled document whose GraphQL source is known at compile time.
+#[derive(Clone)]
+pub struct KnownDocument {
+    /// GraphQL sources of the document and of its resolved interpolations.
+    pub sources: Vec<String>,
+    /// Whether every interpolation of the document could be resolved.
+    pub is_static: bool,
 }
 
-#[derive(Error, Debug, Diagnostic)]
-#[error("{}", self.ty)]
-#[diagnostic(code("GraphQL Error"))]
-pub struct PrettyError {
-    pub ty: String,
-    #[source_code]
-    pub src: NamedSource,
-    #[label("{}", self.ty)]
-    pub span: SourceSpan,
+pub struct GraphQLError {
+    pub message: String,
+    pub index: usize,
+    pub len: usize,
 }
@@ -0,0 +1,377 @@
+// built-ins
+use std::collections::{HashMap, HashSet};
+
+// helpers
+use crate::ast::{get_fragment_spreads, get_unknown_fragments};
+
+// structs
+use super::{DocumentTransform, TransformContext};
+use crate::ast::{
+    Definition, Document, Field, FragmentDefinition, InlineFragment, Selection, SelectionSet,
+};
+
+const CLIENT_ONLY_DIRECTIVES: [&str; 3] = ["client", "export", "connection"];
+
+fn visit_selection_sets(
+    selection_set: &mut SelectionSet,
+    visit: &mut impl FnMut(&mut SelectionSet),
+) {
+    visit(selection_set);
+    for selection in selection_set.selections.iter_mut() {
+        let nested = match selection {
+            Selection::Field(field) => field.selection_set.as_mut(),
+            Selection::InlineFragment(inline_fragment) => inline_fragment.selection_set.as_mut(),
+            Selection::FragmentSpread(_) => None,
+        };
+        if let Some(nested) = nested {
+            visit_selection_sets(nested, visit);
+        }
+    }
+}
+
+fn add_typename(selection_set: &mut SelectionSet, is_root: bool) {
+    let has_typename = selection_set.selections.iter().any(
+        |selection| matches!(selection, Selection::Field(field) if field.name == "__typename"),
+    );
+    if !is_root && !has_typename {
+        selection_set.selections.push(Selection::Field(Field {
+            alias: None,
+            name: "__typename".into(),
+            arguments: vec![],
+            directives: vec![],
+            selection_set: None,
+        }));
+    }
+
+    for selection in selection_set.selections.iter_mut() {
+        match selection {
+            Selection::Field(field) => {
+                if let Some(nested) = field.selection_set.as_mut() {
+                    add_typename(nested, false);
+                }
+            }
+            // inline fragments select on the type of their parent
+            Selection::InlineFragment(inline_fragment) => {
+                if let Some(nested) = inline_fragment.selection_set.as_mut() {
+                    add_typename(nested, is_root);
+                }
+            }
+            Selection::FragmentSpread(_) => {}
+        }
+    }
+}
+
+/// Adds `__typename` to every selection set but the root of operations,
+/// like apollo client's `addTypename`.
+pub struct AddTypename;
+
+impl DocumentTransform for AddTypename {
+    fn transform(&mut self, document: &mut Document, _: &TransformContext) -> Result<(), String> {
+        for definition in document.definitions.iter_mut() {
+            match definition {
+                Definition::Operation(operation) => {
+                    if let Some(selection_set) = operation.selection_set.as_mut() {
+                        add_typename(selection_set, true);
+                    }
+                }
+                Definition::Fragment(fragment) => {
+                    if let Some(selection_set) = fragment.selection_set.as_mut() {
+                        add_typename(selection_set, false);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn get_document_spreads(document: &Document) -> HashSet<String> {
+    let mut spreads = vec![];
+    for definition in &document.definitions {
+        let selection_set = match definition {
+            Definition::Operation(operation) => operation.selection_set.as_ref(),
+            Definition::Fragment(fragment) => fragment.selection_set.as_ref(),
+        };
+        if let Some(selection_set) = selection_set {
+            get_fragment_spreads(selection_set, &mut spreads);
+        }
+    }
+    spreads.into_iter().collect()
+}
+
+fn is_empty_selection_set(selection_set: &Option<SelectionSet>) -> bool {
+    selection_set
+        .as_ref()
+        .is_some_and(|selection_set| selection_set.selections.is_empty())
+}
+
+/// Removes the selections left without selections of their own, and the
+/// spreads of removed fragments.
+fn remove_empty_selections(selection_set: &mut SelectionSet, removed_fragments: &HashSet<String>) {
+    for selection in selection_set.selections.iter_mut() {
+        let nested = match selection {
+            Selection::Field(field) => field.selection_set.as_mut(),
+            Selection::InlineFragment(inline_fragment) => inline_fragment.selection_set.as_mut(),
+            Selection::FragmentSpread(_) => None,
+        };
+        if let Some(nested) = nested {
+            remove_empty_selections(nested, removed_fragments);
+        }
+    }
+
+    selection_set
+        .selections
+        .retain(|selection| match selection {
+            Selection::Field(field) => !is_empty_selection_set(&field.selection_set),
+            Selection::InlineFragment(inline_fragment) => {
+                !is_empty_selection_set(&inline_fragment.selection_set)
+            }
+            Selection::FragmentSpread(spread) => !removed_fragments.contains(&spread.name),
+        });
+}
+
+/// Removes `@client` fields and the directives apollo client handles
+/// itself, leaving what the server is sent. Fields, operations and fragments
+/// left without selections are removed with them, as are the fragments only
+/// spread by what was removed.
+pub struct RemoveClientFields;
+
+impl DocumentTransform for RemoveClientFields {
+    fn transform(&mut self, document: &mut Document, _: &TransformContext) -> Result<(), String> {
+        let remove_client_fields = &mut |selection_set: &mut SelectionSet| {
+            selection_set
+                .selections
+                .retain(|selection| match selection {
+                    Selection::Field(field) => !field
+                        .directives
+                        .iter()
+                        .any(|directive| directive.name == "client"),
+                    _ => true,
+                });
+            for selection in selection_set.selections.iter_mut() {
+                let directives = match selection {
+                    Selection::Field(field) => &mut field.directives,
+                    Selection::FragmentSpread(spread) => &mut spread.directives,
+                    Selection::InlineFragment(inline_fragment) => &mut inline_fragment.directives,
+                };
+                directives
+                    .retain(|directive| !CLIENT_ONLY_DIRECTIVES.contains(&directive.name.as_str()));
+            }
+        };
+
+        let spread_fragments = get_document_spreads(document);
+        for definition in document.definitions.iter_mut() {
+            let selection_set = match definition {
+                Definition::Operation(operation) => {
+                    for variable_definition in operation.variable_definitions.iter_mut() {
+                        variable_definition.directives.retain(|directive| {
+                            !CLIENT_ONLY_DIRECTIVES.contains(&directive.name.as_str())
+                        });
+                    }
+                    operation.selection_set.as_mut()
+                }
+                Definition::Fragment(fragment) => fragment.selection_set.as_mut(),
+            };
+            if let Some(selection_set) = selection_set {
+                visit_selection_sets(selection_set, remove_client_fields);
+            }
+        }
+
+        // removing a fragment can empty the selections spreading it in turn
+        let mut removed_fragments = HashSet::new();
+        loop {
+            for definition in document.definitions.iter_mut() {
+                let selection_set = match definition {
+                    Definition::Operation(operation) => operation.selection_set.as_mut(),
+                    Definition::Fragment(fragment) => fragment.selection_set.as_mut(),
+                };
+                if let Some(selection_set) = selection_set {
+                    remove_empty_selections(selection_set, &removed_fragments);
+                }
+            }
+
+            let remaining_spreads = get_document_spreads(document);
+            let definition_count = document.definitions.len();
+            document.definitions.retain(|definition| match definition {
+                Definition::Operation(operation) => {
+                    !is_empty_selection_set(&operation.selection_set)
+                }
+                Definition::Fragment(fragment) => {
+                    let is_unused = spread_fragments.contains(&fragment.name)
+                        && !remaining_spreads.contains(&fragment.name);
+                    if is_empty_selection_set(&fragment.selection_set) || is_unused {
+                        removed_fragments.insert(fragment.name.clone());
+                        return false;
+                    }
+                    true
+                }
+            });
+
+            if document.definitions.len() == definition_count {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Removes fragment definitions no operation of the document spreads.
+pub struct PruneUnusedFragments;
+
+impl DocumentTransform for PruneUnusedFragments {
+    fn transform(
+        &mut self,
+        document: &mut Document,
+        context: &TransformContext,
+    ) -> Result<(), String> {
+        let has_operation = document
+            .definitions
+            .iter()
+            .any(|definition| matches!(definition, Definition::Operation(_)));
+        // fragments of partial documents may be spread by what gets
+        // concatenated to them at runtime
+        if !context.is_static || !has_operation {
+            return Ok(());
+        }
+
+        let mut pending = vec![];
+        for definition in &document.definitions {
+            if let Definition::Operation(operation) = definition {
+                if let Some(selection_set) = &operation.selection_set {
+                    get_fragment_spreads(selection_set, &mut pending);
+                }
+            }
+        }
+
+        let mut used_fragments = HashSet::new();
+        while let Some(name) = pending.pop() {
+            if !used_fragments.insert(name.clone()) {
+                continue;
+            }
+            for definition in &document.definitions {
+                match definition {
+                    Definition::Fragment(fragment) if fragment.name == name => {
+                        if let Some(selection_set) = &fragment.selection_set {
+                            get_fragment_spreads(selection_set, &mut pending);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        document.definitions.retain(|definition| match definition {
+            Definition::Fragment(fragment) => used_fragments.contains(&fragment.name),
+            Definition::Operation(_) => true,
+        });
+
+        Ok(())
+    }
+}
+
+/// Fails documents spreading fragments they don't define, once nothing
+/// else can be concatenated to them.
+pub struct NoUnknownFragments;
+
+impl DocumentTransform for NoUnknownFragments {
+    fn transform(
+        &mut self,
+        document: &mut Document,
+        context: &TransformContext,
+    ) -> Result<(), String> {
+        if !context.is_static {
+            return Ok(());
+        }
+
+        match get_unknown_fragments(document).first() {
+            Some(name) => Err(format!("Unknown fragment \"{}\"", name)),
+            None => Ok(()),
+        }
+    }
+}
+
+fn inline_spreads(
+    selection_set: &mut SelectionSet,
+    fragments: &HashMap<String, FragmentDefinition>,
+    inlining: &mut Vec<String>,
+) {
+    for selection in selection_set.selections.iter_mut() {
+        let nested = match selection {
+            Selection::Field(field) => field.selection_set.as_mut(),
+            Selection::InlineFragment(inline_fragment) => inline_fragment.selection_set.as_mut(),
+            Selection::FragmentSpread(spread) => {
+                // unknown and cyclic spreads are left for the validation to report
+                let Some(fragment) = fragments.get(&spread.name) else {
+                    continue;
+                };
+                let (Some(type_condition), Some(fragment_selection_set)) =
+                    (&fragment.type_condition, &fragment.selection_set)
+                else {
+                    continue;
+                };
+                if inlining.contains(&spread.name) {
+                    continue;
+                }
+
+                let mut inlined_selection_set = fragment_selection_set.clone();
+                inlining.push(spread.name.clone());
+                inline_spreads(&mut inlined_selection_set, fragments, inlining);
+                inlining.pop();
+
+                let mut directives = spread.directives.clone();
+                directives.extend(fragment.directives.iter().cloned());
+                *selection = Selection::InlineFragment(InlineFragment {
+                    type_condition: Some(type_condition.clone()),
+                    directives,
+                    selection_set: Some(inlined_selection_set),
+                });
+                continue;
+            }
+        };
+        if let Some(nested) = nested {
+            inline_spreads(nested, fragments, inlining);
+        }
+    }
+}
+
+/// Replaces the fragment spreads of operations with inline fragments of the
+/// same type condition and directives, and removes the fragment definitions.
+pub struct InlineFragments;
+
+impl DocumentTransform for InlineFragments {
+    fn transform(
+        &mut self,
+        document: &mut Document,
+        context: &TransformContext,
+    ) -> Result<(), String> {
+        let has_operation = document
+            .definitions
+            .iter()
+            .any(|definition| matches!(definition, Definition::Operation(_)));
+        if !context.is_static || !has_operation {
+            return Ok(());
+        }
+
+        let mut fragments = HashMap::new();
+        for definition in &document.definitions {
+            if let Definition::Fragment(fragment) = definition {
+                fragments
+                    .entry(fragment.name.clone())
+                    .or_insert_with(|| fragment.clone());
+            }
+        }
+
+        document
+            .definitions
+            .retain(|definition| matches!(definition, Definition::Operation(_)));
+        for definition in document.definitions.iter_mut() {
+            if let Definition::Operation(operation) = definition {
+                if let Some(selection_set) = operation.selection_set.as_mut() {
+                    inline_spreads(selection_set, &fragments, &mut vec![]);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+// built-ins
+use std::{collections::HashMap, sync::Arc};
+
+// modules
+mod builtins;
+mod normalize;
+
+// helpers
+use builtins::{
+    AddTypename, InlineFragments, NoUnknownFragments, PruneUnusedFragments, RemoveClientFields,
+};
+use normalize::Normalize;
+
+// structs
+use crate::ast::Document;
+
+pub struct TransformContext<'a> {
+    pub file_path: &'a str,
+    /// Whether the document holds every definition it will be sent with,
+    /// i.e. nothing gets concatenated to it at runtime.
+    pub is_static: bool,
+}
+
+/// A pass over compiled documents, run in the order of
+/// `GraphQLTagConfig::transforms` before documents are emitted.
+pub trait DocumentTransform {
+    fn transform(
+        &mut self,
+        document: &mut Document,
+        context: &TransformContext,
+    ) -> Result<(), String>;
+}
+
+/// Creates a transform, so every program gets passes with a fresh state.
+pub type TransformFactory = Arc<dyn Fn() -> Box<dyn DocumentTransform>>;
+
+/// Creates transforms from the names used in the plugin config. Builds
+/// embedding the transform can register their own passes next to the
+/// built-in ones.
+pub struct TransformRegistry {
+    factories: HashMap<String, TransformFactory>,
+}
+
+impl Default for TransformRegistry {
+    fn default() -> Self {
+        let mut registry = Self {
+            factories: HashMap::new(),
+        };
+        registry.register("addTypename", || Box::new(AddTypename));
+        registry.register("removeClientFields", || Box::new(RemoveClientFields));
+        registry.register("pruneUnusedFragments", || Box::new(PruneUnusedFragments));
+        registry.register("noUnknownFragments", || Box::new(NoUnknownFragments));
+        registry.register("inlineFragments", || Box::new(InlineFragments));
+        registry.register("normalize", || Box::new(Normalize));
+        registry
+    }
+}
+
+impl TransformRegistry {
+    pub fn register(
+        &mut self,
+        name: &str,
+        factory: impl Fn() -> Box<dyn DocumentTransform> + 'static,
+    ) {
+        self.factories.insert(name.into(), Arc::new(factory));
+    }
+
+    pub fn get(&self, name: &str) -> Option<TransformFactory> {
+        self.factories.get(name).cloned()
+    }
+
+    pub fn create(&self, name: &str) -> Option<Box<dyn DocumentTransform>> {
+        self.factories.get(name).map(|factory| factory())
+    }
+}
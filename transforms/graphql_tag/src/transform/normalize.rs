@@ -0,0 +1,170 @@
+// helpers
+use crate::{ast::print_selection, parser::utils::strip_ignored_characters};
+
+// structs
+use super::{DocumentTransform, TransformContext};
+use crate::ast::{Definition, Document, Selection, SelectionSet};
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+enum SelectionKind {
+    Field,
+    FragmentSpread,
+    InlineFragment,
+}
+
+struct NormalizedSelection {
+    kind: SelectionKind,
+    head: String,
+    selection: Selection,
+    selections: Option<Vec<NormalizedSelection>>,
+}
+
+/// Returns `selection` without its selection set, along with the minified
+/// text selections are merged and sorted by.
+fn get_head(selection: &Selection) -> (Selection, String) {
+    let mut selection = selection.clone();
+    match &mut selection {
+        Selection::Field(field) => field.selection_set = None,
+        Selection::InlineFragment(inline_fragment) => inline_fragment.selection_set = None,
+        Selection::FragmentSpread(_) => {}
+    }
+
+    let head = print_selection(&selection);
+    let head = strip_ignored_characters(head.clone()).unwrap_or(head);
+    (selection, head)
+}
+
+fn collect_selections(
+    selection_set: &SelectionSet,
+    parent_type: Option<&str>,
+) -> Vec<NormalizedSelection> {
+    let mut selections = vec![];
+
+    for selection in &selection_set.selections {
+        match selection {
+            Selection::Field(field) => {
+                let (selection, head) = get_head(selection);
+                selections.push(NormalizedSelection {
+                    kind: SelectionKind::Field,
+                    head,
+                    selection,
+                    selections: field
+                        .selection_set
+                        .as_ref()
+                        .map(|selection_set| collect_selections(selection_set, None)),
+                });
+            }
+            Selection::FragmentSpread(_) => {
+                let (selection, head) = get_head(selection);
+                selections.push(NormalizedSelection {
+                    kind: SelectionKind::FragmentSpread,
+                    head,
+                    selection,
+                    selections: None,
+                });
+            }
+            Selection::InlineFragment(inline_fragment) => {
+                let Some(fragment_selection_set) = &inline_fragment.selection_set else {
+                    continue;
+                };
+                let type_condition = inline_fragment.type_condition.as_deref();
+
+                // without directives, a fragment on the type already selected
+                // on adds nothing over its own selections
+                let is_redundant = inline_fragment.directives.is_empty()
+                    && (type_condition.is_none() || type_condition == parent_type);
+                if is_redundant {
+                    selections.extend(collect_selections(fragment_selection_set, parent_type));
+                    continue;
+                }
+
+                let (selection, head) = get_head(selection);
+                selections.push(NormalizedSelection {
+                    kind: SelectionKind::InlineFragment,
+                    head,
+                    selection,
+                    selections: Some(collect_selections(
+                        fragment_selection_set,
+                        type_condition.or(parent_type),
+                    )),
+                });
+            }
+        }
+    }
+
+    selections
+}
+
+fn merge_selections(selections: Vec<NormalizedSelection>) -> Vec<NormalizedSelection> {
+    let mut merged: Vec<NormalizedSelection> = vec![];
+
+    for selection in selections {
+        let existing = merged
+            .iter_mut()
+            .find(|existing| existing.kind == selection.kind && existing.head == selection.head);
+        match existing {
+            Some(existing) => {
+                if let (Some(existing_selections), Some(selections)) =
+                    (existing.selections.as_mut(), selection.selections)
+                {
+                    existing_selections.extend(selections);
+                }
+            }
+            None => merged.push(selection),
+        }
+    }
+
+    for selection in merged.iter_mut() {
+        selection.selections = selection.selections.take().map(merge_selections);
+    }
+    merged.sort_by(|a, b| (a.kind, &a.head).cmp(&(b.kind, &b.head)));
+
+    merged
+}
+
+fn create_selection_set(selections: Vec<NormalizedSelection>) -> SelectionSet {
+    SelectionSet {
+        selections: selections
+            .into_iter()
+            .map(|normalized| {
+                let mut selection = normalized.selection;
+                let selection_set = normalized.selections.map(create_selection_set);
+                match &mut selection {
+                    Selection::Field(field) => field.selection_set = selection_set,
+                    Selection::InlineFragment(inline_fragment) => {
+                        inline_fragment.selection_set = selection_set
+                    }
+                    Selection::FragmentSpread(_) => {}
+                }
+                selection
+            })
+            .collect(),
+    }
+}
+
+/// Rewrites selection sets, merging fields selected more than once with the
+/// same alias, arguments and directives, flattening inline fragments that
+/// don't narrow the selected type, and sorting selections.
+pub struct Normalize;
+
+impl DocumentTransform for Normalize {
+    fn transform(&mut self, document: &mut Document, _: &TransformContext) -> Result<(), String> {
+        for definition in document.definitions.iter_mut() {
+            let (selection_set, parent_type) = match definition {
+                Definition::Operation(operation) => (operation.selection_set.as_mut(), None),
+                Definition::Fragment(fragment) => (
+                    fragment.selection_set.as_mut(),
+                    fragment.type_condition.as_deref(),
+                ),
+            };
+            let Some(selection_set) = selection_set else {
+                continue;
+            };
+
+            let selections = merge_selections(collect_selections(selection_set, parent_type));
+            *selection_set = create_selection_set(selections);
+        }
+
+        Ok(())
+    }
+}
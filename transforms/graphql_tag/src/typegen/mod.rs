@@ -0,0 +1,461 @@
+// built-ins
+use std::collections::{HashMap, HashSet};
+
+// libs
+use swc_common::{BytePos, FileName::Anon, SourceFile, Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_parser::{parse_file_as_expr, Syntax, TsConfig};
+use swc_ecma_visit::{VisitMut, VisitMutWith};
+
+// modules
+pub mod schema;
+
+// structs
+use crate::ast::{
+    Definition, Directive, Document, FragmentDefinition, OperationDefinition, OperationType,
+    Selection, SelectionSet,
+};
+use schema::{Schema, SchemaTypeKind, TypeRef};
+
+pub struct OperationTypes {
+    pub result: String,
+    pub variables: String,
+}
+
+#[derive(Default)]
+struct ObjectShape {
+    fields: Vec<(String, FieldShape)>,
+    conditional: Vec<ObjectShape>,
+}
+
+struct FieldShape {
+    ty: TypeRef,
+    leaf: Option<String>,
+    object: ObjectShape,
+    optional: bool,
+}
+
+struct SpanRemover;
+
+impl VisitMut for SpanRemover {
+    fn visit_mut_span(&mut self, span: &mut Span) {
+        *span = DUMMY_SP;
+    }
+}
+
+struct TypegenContext<'a> {
+    schema: &'a Schema,
+    scalars: &'a HashMap<String, String>,
+    fragments: HashMap<&'a str, &'a FragmentDefinition>,
+    // spreads of fragments interpolated at runtime can't be typed statically
+    has_interpolations: bool,
+}
+
+/// Why types couldn't be generated: what the schema or document is missing,
+/// or `None` when the document is only complete at runtime.
+type TypegenResult<T> = Result<T, Option<String>>;
+
+pub fn is_typescript_file(file_path: &str) -> bool {
+    [".ts", ".tsx", ".mts", ".cts"]
+        .iter()
+        .any(|extension| file_path.ends_with(extension))
+}
+
+pub fn generate_operation_types(
+    document: &Document,
+    schema: &Schema,
+    scalars: &HashMap<String, String>,
+    has_interpolations: bool,
+) -> Result<Option<OperationTypes>, String> {
+    let mut operations = vec![];
+    let mut fragments = HashMap::new();
+
+    for definition in &document.definitions {
+        match definition {
+            Definition::Operation(operation) => operations.push(operation),
+            Definition::Fragment(fragment) => {
+                fragments.insert(fragment.name.as_str(), fragment);
+            }
+        }
+    }
+
+    // TypedDocumentNode can only describe a single operation
+    if operations.len() != 1 {
+        return Ok(None);
+    }
+
+    let ctx = TypegenContext {
+        schema,
+        scalars,
+        fragments,
+        has_interpolations,
+    };
+    let operation = operations.pop().unwrap();
+
+    let types = create_result_type(operation, &ctx).and_then(|result| {
+        Ok(OperationTypes {
+            result,
+            variables: create_variables_type(operation, &ctx)?,
+        })
+    });
+    match types {
+        Ok(types) => Ok(Some(types)),
+        Err(None) => Ok(None),
+        Err(Some(message)) => Err(message),
+    }
+}
+
+fn create_result_type(
+    operation: &OperationDefinition,
+    ctx: &TypegenContext,
+) -> TypegenResult<String> {
+    let root_type = match operation.operation {
+        OperationType::Query => &ctx.schema.query_type,
+        OperationType::Mutation => &ctx.schema.mutation_type,
+        OperationType::Subscription => &ctx.schema.subscription_type,
+    };
+
+    let mut shape = ObjectShape::default();
+    collect_selection_set(
+        operation.selection_set.as_ref().ok_or(None)?,
+        root_type,
+        &mut shape,
+        ctx,
+        &mut HashSet::new(),
+    )?;
+
+    Ok(print_object_shape(&shape))
+}
+
+fn create_variables_type(
+    operation: &OperationDefinition,
+    ctx: &TypegenContext,
+) -> TypegenResult<String> {
+    let mut variables = vec![];
+    for variable_def in &operation.variable_definitions {
+        let ty = variable_def
+            .ty
+            .as_ref()
+            .and_then(TypeRef::from_ast_type)
+            .ok_or(None)?;
+        let optional = !matches!(ty, TypeRef::NonNull(_)) || variable_def.default_value.is_some();
+        let input = create_input_type(ty.named_type(), ctx, &mut vec![]).map_err(|message| {
+            message.map(|message| format!("{} of variable \"${}\"", message, variable_def.variable))
+        })?;
+        variables.push(format!(
+            "{}{}: {}",
+            variable_def.variable,
+            if optional { "?" } else { "" },
+            wrap_type(&ty, &input)
+        ));
+    }
+
+    if variables.is_empty() {
+        return Ok("{ [key: string]: never }".into());
+    }
+
+    Ok(format!("{{ {} }}", variables.join("; ")))
+}
+
+fn create_input_type(
+    name: &str,
+    ctx: &TypegenContext,
+    stack: &mut Vec<String>,
+) -> TypegenResult<String> {
+    let ty = ctx
+        .schema
+        .get_type(name)
+        .ok_or_else(|| format!("unknown type \"{}\"", name))?;
+    match ty.kind {
+        SchemaTypeKind::Scalar | SchemaTypeKind::Enum => create_leaf_type(name, ctx).ok_or(None),
+        SchemaTypeKind::InputObject => {
+            // recursive input types are left open instead of expanding forever
+            if stack.iter().any(|visited| visited == name) {
+                return Ok("unknown".into());
+            }
+            stack.push(name.into());
+
+            let mut fields = vec![];
+            for (field_name, field_ty, has_default) in &ty.input_fields {
+                let optional = !matches!(field_ty, TypeRef::NonNull(_)) || *has_default;
+                let input = create_input_type(field_ty.named_type(), ctx, stack)?;
+                fields.push(format!(
+                    "{}{}: {}",
+                    field_name,
+                    if optional { "?" } else { "" },
+                    wrap_type(field_ty, &input)
+                ));
+            }
+
+            stack.pop();
+            Ok(format!("{{ {} }}", fields.join("; ")))
+        }
+        _ => Err(Some(format!("type \"{}\" is not an input type", name))),
+    }
+}
+
+fn create_leaf_type(name: &str, ctx: &TypegenContext) -> Option<String> {
+    if let Some(scalar) = ctx.scalars.get(name) {
+        return Some(scalar.clone());
+    }
+
+    let ty = ctx.schema.get_type(name)?;
+    match ty.kind {
+        SchemaTypeKind::Enum => Some(
+            ty.enum_values
+                .iter()
+                .map(|value| format!("\"{}\"", value))
+                .collect::<Vec<String>>()
+                .join(" | "),
+        ),
+        SchemaTypeKind::Scalar => Some(
+            match name {
+                "ID" | "String" => "string",
+                "Int" | "Float" => "number",
+                "Boolean" => "boolean",
+                _ => "unknown",
+            }
+            .into(),
+        ),
+        _ => None,
+    }
+}
+
+fn is_conditional(directives: &[Directive]) -> bool {
+    directives
+        .iter()
+        .any(|directive| directive.name == "include" || directive.name == "skip")
+}
+
+fn collect_selection_set(
+    selection_set: &SelectionSet,
+    parent: &str,
+    shape: &mut ObjectShape,
+    ctx: &TypegenContext,
+    visited_fragments: &mut HashSet<String>,
+) -> TypegenResult<()> {
+    for selection in &selection_set.selections {
+        match selection {
+            Selection::Field(field) => {
+                let name = &field.name;
+                let key = field.alias.as_ref().unwrap_or(name).clone();
+                let optional = is_conditional(&field.directives);
+
+                if name == "__typename" {
+                    let parent_type = ctx
+                        .schema
+                        .get_type(parent)
+                        .ok_or_else(|| format!("unknown type \"{}\"", parent))?;
+                    let typename = if parent_type.kind == SchemaTypeKind::Object {
+                        format!("\"{}\"", parent)
+                    } else {
+                        parent_type
+                            .members
+                            .iter()
+                            .map(|member| format!("\"{}\"", member))
+                            .collect::<Vec<String>>()
+                            .join(" | ")
+                    };
+                    add_field(
+                        shape,
+                        key,
+                        FieldShape {
+                            ty: TypeRef::NonNull(Box::new(TypeRef::Named("String".into()))),
+                            leaf: Some(typename),
+                            object: ObjectShape::default(),
+                            optional,
+                        },
+                    );
+                    continue;
+                }
+
+                let ty = ctx
+                    .schema
+                    .field_type(parent, name)
+                    .ok_or_else(|| format!("unknown field \"{}\" on type \"{}\"", name, parent))?
+                    .clone();
+                let mut field_shape = FieldShape {
+                    ty: ty.clone(),
+                    leaf: None,
+                    object: ObjectShape::default(),
+                    optional,
+                };
+
+                match &field.selection_set {
+                    Some(field_selection_set) => collect_selection_set(
+                        field_selection_set,
+                        ty.named_type(),
+                        &mut field_shape.object,
+                        ctx,
+                        visited_fragments,
+                    )?,
+                    None => {
+                        let leaf = create_leaf_type(ty.named_type(), ctx).ok_or_else(|| {
+                            format!(
+                                "field \"{}\" on type \"{}\" has no selection set for its type \"{}\"",
+                                name,
+                                parent,
+                                ty.named_type()
+                            )
+                        })?;
+                        field_shape.leaf = Some(leaf);
+                    }
+                }
+
+                add_field(shape, key, field_shape);
+            }
+            Selection::FragmentSpread(fragment_spread) => {
+                let name = &fragment_spread.name;
+                let fragment = ctx.fragments.get(name.as_str()).ok_or_else(|| {
+                    (!ctx.has_interpolations).then(|| format!("unknown fragment \"{}\"", name))
+                })?;
+                if !visited_fragments.insert(name.clone()) {
+                    return Err(Some(format!("fragment \"{}\" spreads itself", name)));
+                }
+
+                collect_conditional(
+                    fragment.selection_set.as_ref().ok_or(None)?,
+                    parent,
+                    fragment.type_condition.as_ref().ok_or(None)?,
+                    is_conditional(&fragment_spread.directives),
+                    shape,
+                    ctx,
+                    visited_fragments,
+                )?;
+
+                visited_fragments.remove(name);
+            }
+            Selection::InlineFragment(inline_fragment) => {
+                let type_condition = inline_fragment.type_condition.as_deref().unwrap_or(parent);
+                collect_conditional(
+                    inline_fragment.selection_set.as_ref().ok_or(None)?,
+                    parent,
+                    type_condition,
+                    is_conditional(&inline_fragment.directives),
+                    shape,
+                    ctx,
+                    visited_fragments,
+                )?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn collect_conditional(
+    selection_set: &SelectionSet,
+    parent: &str,
+    type_condition: &str,
+    skippable: bool,
+    shape: &mut ObjectShape,
+    ctx: &TypegenContext,
+    visited_fragments: &mut HashSet<String>,
+) -> TypegenResult<()> {
+    if type_condition == parent && !skippable {
+        return collect_selection_set(selection_set, parent, shape, ctx, visited_fragments);
+    }
+
+    let mut conditional_shape = ObjectShape::default();
+    collect_selection_set(
+        selection_set,
+        type_condition,
+        &mut conditional_shape,
+        ctx,
+        visited_fragments,
+    )?;
+    shape.conditional.push(conditional_shape);
+
+    Ok(())
+}
+
+fn add_field(shape: &mut ObjectShape, key: String, field: FieldShape) {
+    match shape.fields.iter_mut().find(|(name, _)| *name == key) {
+        Some((_, existing)) => {
+            existing.optional = existing.optional && field.optional;
+            let ObjectShape {
+                fields,
+                conditional,
+            } = field.object;
+            for (key, field) in fields {
+                add_field(&mut existing.object, key, field);
+            }
+            existing.object.conditional.extend(conditional);
+        }
+        None => shape.fields.push((key, field)),
+    }
+}
+
+fn wrap_type(ty: &TypeRef, inner: &str) -> String {
+    match ty {
+        TypeRef::NonNull(ty) => wrap_non_null_type(ty, inner),
+        _ => format!("{} | null", wrap_non_null_type(ty, inner)),
+    }
+}
+
+fn wrap_non_null_type(ty: &TypeRef, inner: &str) -> String {
+    match ty {
+        TypeRef::Named(_) => inner.into(),
+        TypeRef::List(ty) => format!("Array<{}>", wrap_type(ty, inner)),
+        TypeRef::NonNull(ty) => wrap_non_null_type(ty, inner),
+    }
+}
+
+fn print_object_shape(shape: &ObjectShape) -> String {
+    let fields: Vec<String> = shape
+        .fields
+        .iter()
+        .map(|(key, field)| {
+            let inner = match &field.leaf {
+                Some(leaf) => leaf.clone(),
+                None => print_object_shape(&field.object),
+            };
+            format!(
+                "{}{}: {}",
+                key,
+                if field.optional { "?" } else { "" },
+                wrap_type(&field.ty, &inner)
+            )
+        })
+        .collect();
+
+    let mut object = format!("{{ {} }}", fields.join("; "));
+    if fields.is_empty() {
+        object = "{}".into();
+    }
+
+    for conditional in &shape.conditional {
+        object = format!("{} & ({} | {{}})", object, print_object_shape(conditional));
+    }
+
+    object
+}
+
+pub fn create_typed_document_node(expr: Expr, types: OperationTypes, span: Span) -> Expr {
+    let source = format!(
+        "null as import(\"@graphql-typed-document-node/core\").TypedDocumentNode<{}, {}>",
+        types.result, types.variables
+    );
+    let source_file = SourceFile::new(Anon, false, Anon, source, BytePos(1));
+
+    let mut type_ann = match parse_file_as_expr(
+        &source_file,
+        Syntax::Typescript(TsConfig::default()),
+        Default::default(),
+        None,
+        &mut vec![],
+    ) {
+        Ok(parsed) => match *parsed {
+            Expr::TsAs(ts_as) => ts_as.type_ann,
+            _ => return expr,
+        },
+        Err(_) => return expr,
+    };
+    type_ann.visit_mut_with(&mut SpanRemover);
+
+    Expr::TsAs(TsAsExpr {
+        span,
+        expr: Box::new(expr),
+        type_ann,
+    })
+}
@@ -0,0 +1,322 @@
+// built-ins
+use std::{
+    collections::HashMap,
+    fs,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::SystemTime,
+};
+
+// libs
+use apollo_parser::{
+    cst::{Definition, FieldsDefinition, ImplementsInterfaces, InputFieldsDefinition, Type},
+    Parser,
+};
+use once_cell::sync::Lazy;
+
+// structs
+use crate::ast;
+
+type LoadedSchema = (Option<SystemTime>, Result<Arc<Schema>, String>);
+
+// schemas by path, with the modification time they were loaded at, shared
+// by every file compiled in the process
+static SCHEMAS: Lazy<Mutex<HashMap<PathBuf, LoadedSchema>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TypeRef {
+    Named(String),
+    List(Box<TypeRef>),
+    NonNull(Box<TypeRef>),
+}
+
+impl TypeRef {
+    pub fn from_type(ty: Type) -> Option<Self> {
+        match ty {
+            Type::NamedType(named_type) => Some(TypeRef::Named(named_type.name()?.text().into())),
+            Type::ListType(list_type) => Some(TypeRef::List(Box::new(TypeRef::from_type(
+                list_type.ty()?,
+            )?))),
+            Type::NonNullType(non_null_type) => {
+                let inner = match non_null_type.named_type() {
+                    Some(named_type) => TypeRef::Named(named_type.name()?.text().into()),
+                    None => TypeRef::List(Box::new(TypeRef::from_type(
+                        non_null_type.list_type()?.ty()?,
+                    )?)),
+                };
+                Some(TypeRef::NonNull(Box::new(inner)))
+            }
+        }
+    }
+
+    pub fn from_ast_type(ty: &ast::Type) -> Option<Self> {
+        match ty {
+            ast::Type::Named(name) => Some(TypeRef::Named(name.clone())),
+            ast::Type::List(ty) => Some(TypeRef::List(Box::new(TypeRef::from_ast_type(
+                ty.as_ref()?,
+            )?))),
+            ast::Type::NonNull(ty) => Some(TypeRef::NonNull(Box::new(TypeRef::from_ast_type(ty)?))),
+        }
+    }
+
+    pub fn named_type(&self) -> &str {
+        match self {
+            TypeRef::Named(name) => name,
+            TypeRef::List(ty) | TypeRef::NonNull(ty) => ty.named_type(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SchemaTypeKind {
+    Scalar,
+    Object,
+    Interface,
+    Union,
+    Enum,
+    InputObject,
+}
+
+#[derive(Clone, Debug)]
+pub struct SchemaType {
+    pub kind: SchemaTypeKind,
+    pub fields: Vec<(String, TypeRef)>,
+    pub input_fields: Vec<(String, TypeRef, bool)>,
+    pub interfaces: Vec<String>,
+    pub members: Vec<String>,
+    pub enum_values: Vec<String>,
+}
+
+impl SchemaType {
+    fn new(kind: SchemaTypeKind) -> Self {
+        Self {
+            kind,
+            fields: vec![],
+            input_fields: vec![],
+            interfaces: vec![],
+            members: vec![],
+            enum_values: vec![],
+        }
+    }
+}
+
+pub struct Schema {
+    pub types: HashMap<String, SchemaType>,
+    pub query_type: String,
+    pub mutation_type: String,
+    pub subscription_type: String,
+}
+
+impl Schema {
+    /// Loads the schema at `path`, reading it again only when it was
+    /// modified since it was last loaded.
+    pub fn load(path: &str) -> Result<Arc<Self>, String> {
+        let modified = fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok();
+
+        let mut schemas = SCHEMAS.lock().unwrap();
+        if let Some((loaded_modified, schema)) = schemas.get(&PathBuf::from(path)) {
+            if modified.is_some() && *loaded_modified == modified {
+                return schema.clone();
+            }
+        }
+
+        let schema = fs::read_to_string(path)
+            .map_err(|error| format!("Could not read schema at \"{}\": {}", path, error))
+            .and_then(|sdl| Self::parse(&sdl))
+            .map(Arc::new);
+        schemas.insert(path.into(), (modified, schema.clone()));
+        schema
+    }
+
+    pub fn parse(sdl: &str) -> Result<Self, String> {
+        let ast = Parser::new(sdl).parse();
+        if let Some(error) = ast.errors().next() {
+            return Err(format!(
+                "Invalid schema: At index {}, {} got \"{}\" instead",
+                error.index(),
+                error.message(),
+                error.data()
+            ));
+        }
+
+        let mut schema = Schema {
+            types: HashMap::new(),
+            query_type: "Query".into(),
+            mutation_type: "Mutation".into(),
+            subscription_type: "Subscription".into(),
+        };
+
+        for scalar in ["Int", "Float", "String", "Boolean", "ID"] {
+            schema
+                .types
+                .insert(scalar.into(), SchemaType::new(SchemaTypeKind::Scalar));
+        }
+
+        for definition in ast.document().definitions() {
+            match definition {
+                Definition::SchemaDefinition(schema_def) => {
+                    for root in schema_def.root_operation_type_definitions() {
+                        let (Some(operation_type), Some(named_type)) =
+                            (root.operation_type(), root.named_type())
+                        else {
+                            continue;
+                        };
+                        let Some(name) = named_type.name() else {
+                            continue;
+                        };
+                        let name: String = name.text().into();
+
+                        if operation_type.mutation_token().is_some() {
+                            schema.mutation_type = name;
+                        } else if operation_type.subscription_token().is_some() {
+                            schema.subscription_type = name;
+                        } else {
+                            schema.query_type = name;
+                        }
+                    }
+                }
+                Definition::ScalarTypeDefinition(def) => {
+                    schema.add_type(def.name(), SchemaTypeKind::Scalar);
+                }
+                Definition::ObjectTypeDefinition(def) => {
+                    let ty = schema.add_type(def.name(), SchemaTypeKind::Object);
+                    add_fields(ty, def.fields_definition());
+                    add_interfaces(ty, def.implements_interfaces());
+                }
+                Definition::ObjectTypeExtension(def) => {
+                    let ty = schema.add_type(def.name(), SchemaTypeKind::Object);
+                    add_fields(ty, def.fields_definition());
+                    add_interfaces(ty, def.implements_interfaces());
+                }
+                Definition::InterfaceTypeDefinition(def) => {
+                    let ty = schema.add_type(def.name(), SchemaTypeKind::Interface);
+                    add_fields(ty, def.fields_definition());
+                }
+                Definition::InterfaceTypeExtension(def) => {
+                    let ty = schema.add_type(def.name(), SchemaTypeKind::Interface);
+                    add_fields(ty, def.fields_definition());
+                }
+                Definition::UnionTypeDefinition(def) => {
+                    let ty = schema.add_type(def.name(), SchemaTypeKind::Union);
+                    if let Some(members) = def.union_member_types() {
+                        for member in members.named_types() {
+                            if let Some(name) = member.name() {
+                                ty.members.push(name.text().into());
+                            }
+                        }
+                    }
+                }
+                Definition::EnumTypeDefinition(def) => {
+                    let ty = schema.add_type(def.name(), SchemaTypeKind::Enum);
+                    if let Some(values) = def.enum_values_definition() {
+                        for value in values.enum_value_definitions() {
+                            if let Some(value) = value.enum_value() {
+                                ty.enum_values.push(value.text().to_string());
+                            }
+                        }
+                    }
+                }
+                Definition::InputObjectTypeDefinition(def) => {
+                    let ty = schema.add_type(def.name(), SchemaTypeKind::InputObject);
+                    add_input_fields(ty, def.input_fields_definition());
+                }
+                Definition::InputObjectTypeExtension(def) => {
+                    let ty = schema.add_type(def.name(), SchemaTypeKind::InputObject);
+                    add_input_fields(ty, def.input_fields_definition());
+                }
+                _ => {}
+            }
+        }
+
+        // interfaces resolve to every object type implementing them
+        let implementations: Vec<(String, String)> = schema
+            .types
+            .iter()
+            .flat_map(|(name, ty)| {
+                ty.interfaces
+                    .iter()
+                    .map(move |interface| (interface.clone(), name.clone()))
+            })
+            .collect();
+        for (interface, object) in implementations {
+            if let Some(ty) = schema.types.get_mut(&interface) {
+                ty.members.push(object);
+            }
+        }
+        for ty in schema.types.values_mut() {
+            ty.members.sort();
+        }
+
+        Ok(schema)
+    }
+
+    fn add_type(
+        &mut self,
+        name: Option<apollo_parser::cst::Name>,
+        kind: SchemaTypeKind,
+    ) -> &mut SchemaType {
+        let name: String = name.map(|name| name.text().into()).unwrap_or_default();
+        self.types
+            .entry(name)
+            .or_insert_with(|| SchemaType::new(kind))
+    }
+
+    pub fn get_type(&self, name: &str) -> Option<&SchemaType> {
+        self.types.get(name)
+    }
+
+    pub fn field_type(&self, parent: &str, field: &str) -> Option<&TypeRef> {
+        self.get_type(parent)?
+            .fields
+            .iter()
+            .find(|(name, _)| name == field)
+            .map(|(_, ty)| ty)
+    }
+}
+
+fn add_fields(ty: &mut SchemaType, fields: Option<FieldsDefinition>) {
+    let Some(fields) = fields else {
+        return;
+    };
+
+    for field in fields.field_definitions() {
+        if let (Some(name), Some(field_ty)) = (field.name(), field.ty()) {
+            if let Some(type_ref) = TypeRef::from_type(field_ty) {
+                ty.fields.push((name.text().into(), type_ref));
+            }
+        }
+    }
+}
+
+fn add_input_fields(ty: &mut SchemaType, fields: Option<InputFieldsDefinition>) {
+    let Some(fields) = fields else {
+        return;
+    };
+
+    for field in fields.input_value_definitions() {
+        if let (Some(name), Some(field_ty)) = (field.name(), field.ty()) {
+            if let Some(type_ref) = TypeRef::from_type(field_ty) {
+                ty.input_fields.push((
+                    name.text().into(),
+                    type_ref,
+                    field.default_value().is_some(),
+                ));
+            }
+        }
+    }
+}
+
+fn add_interfaces(ty: &mut SchemaType, interfaces: Option<ImplementsInterfaces>) {
+    let Some(interfaces) = interfaces else {
+        return;
+    };
+
+    for interface in interfaces.named_types() {
+        if let Some(name) = interface.name() {
+            ty.interfaces.push(name.text().into());
+        }
+    }
+}
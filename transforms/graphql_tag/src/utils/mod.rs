@@ -1,9 +1,12 @@
 // libs
-use swc_common::{BytePos, FileName::Anon, SourceFile};
+use swc_common::{BytePos, FileName::Anon, SourceFile, Span};
 use swc_core::atoms::Atom;
 use swc_ecma_ast::*;
 use swc_ecma_parser::parse_file_as_expr;
 
+// structs
+use crate::structs::{GraphQLTagConfig, TemplateSource};
+
 const SOURCE: &str = "(definitions) => {
   const names = {};
   return definitions.filter(definition => {
@@ -63,3 +66,83 @@ pub fn add_unique_fn_to_program(program: &mut Program, unique_fn_name: String) {
         ),
     }
 }
+
+/// Import sources whose default export is the gql tag.
+const DEFAULT_GQL_TAG_SOURCES: [&str; 1] = ["graphql-tag"];
+
+/// Local names of the gql tags an import declaration brings in scope.
+pub fn get_gql_tag_local_names(node: &ImportDecl, config: &GraphQLTagConfig) -> Vec<String> {
+    let import_source = node.src.value.to_string();
+    let valid_import_source = config.import_sources.contains(&import_source)
+        || config.output_profiles.contains_key(&import_source);
+    if !valid_import_source {
+        return vec![];
+    }
+
+    node.specifiers
+        .iter()
+        .filter_map(|import_specifier| match import_specifier {
+            ImportSpecifier::Named(specifier) => {
+                let import_name = match &specifier.imported {
+                    Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+                    Some(ModuleExportName::Str(_)) | None => specifier.local.sym.to_string(),
+                };
+                config
+                    .gql_tag_identifiers
+                    .contains(&import_name)
+                    .then(|| specifier.local.sym.to_string())
+            }
+            // e.g. the default export of graphql-request is `request`
+            ImportSpecifier::Default(specifier) => DEFAULT_GQL_TAG_SOURCES
+                .contains(&import_source.as_str())
+                .then(|| specifier.local.sym.to_string()),
+            ImportSpecifier::Namespace(_) => None,
+        })
+        .collect()
+}
+
+pub fn get_import_bindings(node: &ImportDecl) -> Vec<(String, String)> {
+    node.specifiers
+        .iter()
+        .filter_map(|import_specifier| match import_specifier {
+            ImportSpecifier::Named(specifier) => {
+                let imported = match &specifier.imported {
+                    Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+                    Some(ModuleExportName::Str(str)) => str.value.to_string(),
+                    None => specifier.local.sym.to_string(),
+                };
+                Some((specifier.local.sym.to_string(), imported))
+            }
+            ImportSpecifier::Default(specifier) => {
+                Some((specifier.local.sym.to_string(), "default".into()))
+            }
+            ImportSpecifier::Namespace(_) => None,
+        })
+        .collect()
+}
+
+/// Joins the cooked text of the quasis of a template, the strings
+/// `graphql-tag` gets at runtime.
+pub fn get_template_source(quasis: &[TplElement]) -> TemplateSource {
+    let mut source = TemplateSource::default();
+    for quasi in quasis {
+        source.parts.push((source.text.len(), quasi.span));
+        // quasis with invalid escapes have no cooked text, only tagged
+        // templates allow them
+        source.text += quasi.cooked.as_ref().unwrap_or(&quasi.raw);
+    }
+    source
+}
+
+/// Span in the JS source of `len` bytes at `index` of the text of `source`.
+/// Escape sequences earlier in the quasi shift it, within the quasi.
+pub fn get_source_span(source: &TemplateSource, index: usize, len: usize) -> Option<Span> {
+    let (start, span) = source
+        .parts
+        .iter()
+        .rev()
+        .find(|(start, _)| *start <= index)?;
+    let lo = (span.lo + BytePos((index - start) as u32)).min(span.hi);
+    let hi = (lo + BytePos(len as u32)).min(span.hi);
+    Some(Span::new(lo, hi, span.ctxt))
+}
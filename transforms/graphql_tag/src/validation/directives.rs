@@ -0,0 +1,69 @@
+// libs
+use apollo_parser::cst::{CstNode, Directive, Document};
+
+// helpers
+use crate::validation::create_error;
+
+// structs
+use crate::structs::{DirectivesConfig, GraphQLError};
+
+fn get_edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut distances: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut previous = distances[0];
+        distances[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous + usize::from(a_char != *b_char);
+            previous = distances[j + 1];
+            distances[j + 1] = substitution.min(previous + 1).min(distances[j] + 1);
+        }
+    }
+
+    distances[b.len()]
+}
+
+fn get_suggestion<'a>(name: &str, allowed: &'a [String]) -> Option<&'a str> {
+    allowed
+        .iter()
+        .map(|allowed_name| allowed_name.trim_start_matches('@'))
+        .map(|allowed_name| (get_edit_distance(name, allowed_name), allowed_name))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, allowed_name)| allowed_name)
+}
+
+fn is_listed(name: &str, list: &[String]) -> bool {
+    list.iter()
+        .any(|listed_name| listed_name.trim_start_matches('@') == name)
+}
+
+pub fn validate_directives(document: &Document, config: &DirectivesConfig) -> Vec<GraphQLError> {
+    let mut errors = vec![];
+
+    for directive in document.syntax().descendants().filter_map(Directive::cast) {
+        let Some(name) = directive.name() else {
+            continue;
+        };
+        let name = name.text().to_string();
+
+        let message = if is_listed(&name, &config.deny) {
+            format!("Directive \"@{}\" is not allowed", name)
+        } else if !is_listed(&name, &config.allow) {
+            match get_suggestion(&name, &config.allow) {
+                Some(suggestion) => format!(
+                    "Unknown directive \"@{}\", did you mean \"@{}\"?",
+                    name, suggestion
+                ),
+                None => format!("Unknown directive \"@{}\"", name),
+            }
+        } else {
+            continue;
+        };
+
+        errors.push(create_error(message, &directive));
+    }
+
+    errors
+}
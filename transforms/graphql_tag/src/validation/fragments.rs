@@ -0,0 +1,240 @@
+// built-ins
+use std::collections::{HashMap, HashSet};
+
+// libs
+use apollo_parser::cst::{CstNode, Definition, Document, FragmentSpread, Name};
+
+// helpers
+use crate::{
+    ast::{get_fragment_spreads, get_reachable_fragments, get_unknown_fragments, lower_document},
+    parser::utils::strip_ignored_characters,
+    validation::create_error,
+};
+
+// structs
+use crate::{ast, structs::GraphQLError};
+
+fn get_printed_fragments(document: &Document) -> Vec<(String, String, Name)> {
+    let mut fragments = vec![];
+
+    for definition in document.definitions() {
+        if let Definition::FragmentDefinition(fragment) = definition {
+            let Some(name) = fragment.fragment_name().and_then(|name| name.name()) else {
+                continue;
+            };
+            let source = fragment.source_string();
+            let printed = strip_ignored_characters(source.clone()).unwrap_or(source);
+
+            fragments.push((name.text().to_string(), printed, name));
+        }
+    }
+
+    fragments
+}
+
+fn get_fragment_dependencies(document: &Document) -> HashMap<String, Vec<String>> {
+    let mut dependencies = HashMap::new();
+
+    for definition in document.definitions() {
+        if let Definition::FragmentDefinition(fragment) = definition {
+            let Some(name) = fragment.fragment_name().and_then(|name| name.name()) else {
+                continue;
+            };
+            let spreads = get_fragment_spread_names(&fragment)
+                .iter()
+                .map(|spread_name| spread_name.text().to_string())
+                .collect();
+            dependencies.insert(name.text().to_string(), spreads);
+        }
+    }
+
+    dependencies
+}
+
+/// Reports a conflict between interpolated fragments at the first spread of
+/// the document using the conflicting fragment, directly or through others.
+fn create_interpolated_conflict_error(
+    document: &Document,
+    dependencies: &HashMap<String, Vec<String>>,
+    name: &str,
+) -> GraphQLError {
+    let message = format!(
+        "Fragment \"{}\" is defined with different selections by interpolated documents",
+        name
+    );
+
+    let spread_name = get_fragment_spread_names(document)
+        .into_iter()
+        .find(|spread_name| {
+            get_reachable_fragments(dependencies, vec![spread_name.text().to_string()])
+                .contains(name)
+        });
+    match spread_name {
+        Some(spread_name) => create_error(message, &spread_name),
+        None => GraphQLError {
+            message,
+            index: 0,
+            len: 0,
+        },
+    }
+}
+
+pub fn validate_duplicate_fragments(
+    document: &Document,
+    interpolated_sources: &[String],
+) -> Vec<GraphQLError> {
+    let mut errors = vec![];
+    let mut known_fragments: HashMap<String, String> = HashMap::new();
+    let mut dependencies = get_fragment_dependencies(document);
+    let mut conflicts = vec![];
+
+    for source in interpolated_sources {
+        let interpolated_document = apollo_parser::Parser::new(source).parse().document();
+
+        for (name, printed, _) in get_printed_fragments(&interpolated_document) {
+            match known_fragments.get(&name) {
+                Some(known) if *known != printed && !conflicts.contains(&name) => {
+                    conflicts.push(name)
+                }
+                Some(_) => {}
+                None => {
+                    known_fragments.insert(name, printed);
+                }
+            }
+        }
+        for (name, spreads) in get_fragment_dependencies(&interpolated_document) {
+            dependencies.entry(name).or_insert(spreads);
+        }
+    }
+
+    for name in conflicts {
+        errors.push(create_interpolated_conflict_error(
+            document,
+            &dependencies,
+            &name,
+        ));
+    }
+
+    for (name, printed, name_node) in get_printed_fragments(document) {
+        match known_fragments.get(&name) {
+            Some(known) if *known != printed => errors.push(create_error(
+                format!(
+                    "Fragment \"{}\" is already defined with different selections",
+                    name
+                ),
+                &name_node,
+            )),
+            Some(_) => {}
+            None => {
+                known_fragments.insert(name, printed);
+            }
+        }
+    }
+
+    errors
+}
+
+fn get_fragment_spread_names<N: CstNode>(node: &N) -> Vec<Name> {
+    node.syntax()
+        .descendants()
+        .filter_map(FragmentSpread::cast)
+        .filter_map(|spread| spread.fragment_name().and_then(|name| name.name()))
+        .collect()
+}
+
+pub fn validate_fragment_spreads(
+    document: &Document,
+    interpolated_sources: &[String],
+) -> Vec<GraphQLError> {
+    let mut errors = vec![];
+
+    // the definitions of interpolated documents are appended at runtime
+    // documents that can't be lowered are reported when compiling them
+    let Ok(mut merged_document) = lower_document(document) else {
+        return errors;
+    };
+    for source in interpolated_sources {
+        let interpolated_document = apollo_parser::Parser::new(source).parse().document();
+        if let Ok(lowered_document) = lower_document(&interpolated_document) {
+            merged_document.definitions.extend(
+                lowered_document
+                    .definitions
+                    .into_iter()
+                    .filter(|definition| matches!(definition, ast::Definition::Fragment(_))),
+            );
+        }
+    }
+
+    let unknown_fragments = get_unknown_fragments(&merged_document);
+    for spread_name in get_fragment_spread_names(document) {
+        let name = spread_name.text().to_string();
+        if unknown_fragments.contains(&name) {
+            errors.push(create_error(
+                format!("Unknown fragment \"{}\"", name),
+                &spread_name,
+            ));
+        }
+    }
+
+    let mut has_operation = false;
+    let mut operation_spreads = vec![];
+    let mut fragment_spreads = HashMap::new();
+    for definition in &merged_document.definitions {
+        match definition {
+            ast::Definition::Operation(operation) => {
+                has_operation = true;
+                if let Some(selection_set) = &operation.selection_set {
+                    get_fragment_spreads(selection_set, &mut operation_spreads);
+                }
+            }
+            ast::Definition::Fragment(fragment) => {
+                let mut spreads = vec![];
+                if let Some(selection_set) = &fragment.selection_set {
+                    get_fragment_spreads(selection_set, &mut spreads);
+                }
+                fragment_spreads
+                    .entry(fragment.name.clone())
+                    .or_insert(spreads);
+            }
+        }
+    }
+
+    // fragment only documents are meant to be interpolated elsewhere
+    if !has_operation {
+        return errors;
+    }
+
+    let used_fragments = get_reachable_fragments(&fragment_spreads, operation_spreads);
+    let mut reported_fragments = HashSet::new();
+    for definition in document.definitions() {
+        if let Definition::FragmentDefinition(fragment) = definition {
+            let Some(name) = fragment.fragment_name().and_then(|name| name.name()) else {
+                continue;
+            };
+            let name_text = name.text().to_string();
+            if !used_fragments.contains(&name_text) && reported_fragments.insert(name_text) {
+                errors.push(create_error(
+                    format!("Fragment \"{}\" is never used", name.text()),
+                    &name,
+                ));
+            }
+        }
+    }
+
+    // interpolated fragments aren't part of the template's text
+    for definition in &merged_document.definitions {
+        if let ast::Definition::Fragment(fragment) = definition {
+            if !used_fragments.contains(&fragment.name)
+                && reported_fragments.insert(fragment.name.clone())
+            {
+                errors.push(GraphQLError {
+                    message: format!("Interpolated fragment \"{}\" is never used", fragment.name),
+                    index: 0,
+                    len: 0,
+                });
+            }
+        }
+    }
+
+    errors
+}
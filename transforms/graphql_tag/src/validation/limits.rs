@@ -0,0 +1,216 @@
+// built-ins
+use std::collections::HashMap;
+
+// libs
+use apollo_parser::{
+    cst::{CstNode, Definition, Document, FragmentDefinition, Selection, SelectionSet},
+    Parser, SyntaxNode,
+};
+
+// structs
+use crate::structs::{GraphQLError, LimitsConfig};
+
+struct LimitError {
+    message: String,
+    node: SyntaxNode,
+}
+
+struct LimitsWalker<'a> {
+    limits: &'a LimitsConfig,
+    // fragments by name, and whether they are defined in the validated document
+    fragments: &'a HashMap<String, (FragmentDefinition, bool)>,
+    operation_name: String,
+    path: Vec<String>,
+    visiting: Vec<String>,
+    aliases: usize,
+    field_count: usize,
+    depth_error: Option<LimitError>,
+    aliases_error: Option<LimitError>,
+    field_count_error: Option<LimitError>,
+}
+
+impl<'a> LimitsWalker<'a> {
+    fn create_limit_error(&self, limit: String, node: SyntaxNode) -> Option<LimitError> {
+        Some(LimitError {
+            message: format!(
+                "{} exceeds the maximum {} at \"{}\"",
+                self.operation_name,
+                limit,
+                self.path.join(".")
+            ),
+            node,
+        })
+    }
+
+    fn walk(&mut self, selection_set: &SelectionSet, anchor: Option<&SyntaxNode>) {
+        for selection in selection_set.selections() {
+            match selection {
+                Selection::Field(field) => {
+                    let Some(name) = field.name() else {
+                        continue;
+                    };
+                    let alias = field.alias().and_then(|alias| alias.name());
+                    let key = alias.as_ref().unwrap_or(&name).text().to_string();
+                    // fields of fragments from other documents are reported
+                    // at the spread that pulled them in
+                    let node = anchor.cloned().unwrap_or_else(|| name.syntax().clone());
+
+                    self.path.push(key);
+
+                    if let Some(max_depth) = self.limits.max_depth {
+                        if self.path.len() > max_depth && self.depth_error.is_none() {
+                            self.depth_error = self.create_limit_error(
+                                format!("depth of {}", max_depth),
+                                node.clone(),
+                            );
+                        }
+                    }
+
+                    if let Some(alias) = &alias {
+                        self.aliases += 1;
+                        if let Some(max_aliases) = self.limits.max_aliases {
+                            if self.aliases > max_aliases && self.aliases_error.is_none() {
+                                let alias_node =
+                                    anchor.cloned().unwrap_or_else(|| alias.syntax().clone());
+                                self.aliases_error = self.create_limit_error(
+                                    format!("of {} aliases", max_aliases),
+                                    alias_node,
+                                );
+                            }
+                        }
+                    }
+
+                    self.field_count += self
+                        .limits
+                        .complexity
+                        .get(name.text().as_str())
+                        .copied()
+                        .unwrap_or(1);
+                    if let Some(max_field_count) = self.limits.max_field_count {
+                        if self.field_count > max_field_count && self.field_count_error.is_none() {
+                            self.field_count_error = self.create_limit_error(
+                                format!("field count of {}", max_field_count),
+                                node,
+                            );
+                        }
+                    }
+
+                    if let Some(selection_set) = field.selection_set() {
+                        self.walk(&selection_set, anchor);
+                    }
+                    self.path.pop();
+                }
+                Selection::InlineFragment(inline_fragment) => {
+                    if let Some(selection_set) = inline_fragment.selection_set() {
+                        self.walk(&selection_set, anchor);
+                    }
+                }
+                Selection::FragmentSpread(spread) => {
+                    let Some(name) = spread
+                        .fragment_name()
+                        .and_then(|fragment_name| fragment_name.name())
+                        .map(|name| name.text().to_string())
+                    else {
+                        continue;
+                    };
+                    if self.visiting.contains(&name) {
+                        continue;
+                    }
+                    let fragments = self.fragments;
+                    let Some((fragment, is_local)) = fragments.get(&name) else {
+                        continue;
+                    };
+                    let Some(selection_set) = fragment.selection_set() else {
+                        continue;
+                    };
+
+                    let spread_node = spread.syntax().clone();
+                    let fragment_anchor = match anchor {
+                        Some(anchor) => Some(anchor),
+                        None if !*is_local => Some(&spread_node),
+                        None => None,
+                    };
+                    self.visiting.push(name);
+                    self.walk(&selection_set, fragment_anchor);
+                    self.visiting.pop();
+                }
+            }
+        }
+    }
+}
+
+pub fn validate_limits(
+    document: &Document,
+    limits: &LimitsConfig,
+    interpolated_sources: &[String],
+) -> Vec<GraphQLError> {
+    let mut errors = vec![];
+    if limits.max_depth.is_none()
+        && limits.max_aliases.is_none()
+        && limits.max_field_count.is_none()
+    {
+        return errors;
+    }
+
+    let mut fragments = HashMap::new();
+    let interpolated_documents = interpolated_sources
+        .iter()
+        .map(|source| (Parser::new(source).parse().document(), false));
+    for (fragment_document, is_local) in
+        std::iter::once((document.clone(), true)).chain(interpolated_documents)
+    {
+        for definition in fragment_document.definitions() {
+            if let Definition::FragmentDefinition(fragment) = definition {
+                if let Some(name) = fragment.fragment_name().and_then(|name| name.name()) {
+                    fragments
+                        .entry(name.text().to_string())
+                        .or_insert((fragment, is_local));
+                }
+            }
+        }
+    }
+
+    for definition in document.definitions() {
+        let Definition::OperationDefinition(operation) = definition else {
+            continue;
+        };
+        let Some(selection_set) = operation.selection_set() else {
+            continue;
+        };
+
+        let mut walker = LimitsWalker {
+            limits,
+            fragments: &fragments,
+            operation_name: match operation.name() {
+                Some(name) => format!("Operation \"{}\"", name.text()),
+                None => "Anonymous operation".into(),
+            },
+            path: vec![],
+            visiting: vec![],
+            aliases: 0,
+            field_count: 0,
+            depth_error: None,
+            aliases_error: None,
+            field_count_error: None,
+        };
+        walker.walk(&selection_set, None);
+
+        for error in [
+            walker.depth_error,
+            walker.aliases_error,
+            walker.field_count_error,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            let range = error.node.text_range();
+            errors.push(GraphQLError {
+                message: error.message,
+                index: range.start().into(),
+                len: range.len().into(),
+            });
+        }
+    }
+
+    errors
+}
@@ -0,0 +1,62 @@
+// built-ins
+use std::collections::HashSet;
+
+// libs
+use apollo_parser::cst::{CstNode, Document};
+use regex::Regex;
+
+// modules
+mod directives;
+mod fragments;
+mod limits;
+mod naming;
+
+// helpers
+use directives::validate_directives;
+use fragments::validate_duplicate_fragments;
+use limits::validate_limits;
+
+// re-exports
+pub use fragments::validate_fragment_spreads;
+pub use naming::get_name_pattern;
+use naming::validate_naming;
+
+// structs
+use crate::structs::{GraphQLError, GraphQLTagConfig};
+
+pub fn validate_document(
+    document: &Document,
+    config: &GraphQLTagConfig,
+    operation_name_pattern: Option<&Regex>,
+    operation_names: &mut HashSet<String>,
+    interpolated_sources: &[String],
+) -> Vec<GraphQLError> {
+    let mut errors = vec![];
+
+    errors.extend(validate_naming(
+        document,
+        &config.lint,
+        operation_name_pattern,
+        operation_names,
+    ));
+    errors.extend(validate_duplicate_fragments(document, interpolated_sources));
+    if let Some(directives) = &config.directives {
+        errors.extend(validate_directives(document, directives));
+    }
+    errors.extend(validate_limits(
+        document,
+        &config.limits,
+        interpolated_sources,
+    ));
+
+    errors
+}
+
+pub fn create_error<N: CstNode>(message: String, node: &N) -> GraphQLError {
+    let range = node.syntax().text_range();
+    GraphQLError {
+        message,
+        index: range.start().into(),
+        len: range.len().into(),
+    }
+}
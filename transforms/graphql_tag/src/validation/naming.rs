@@ -0,0 +1,119 @@
+// built-ins
+use std::collections::HashSet;
+
+// libs
+use apollo_parser::cst::{Definition, Document, OperationDefinition};
+use regex::Regex;
+
+// helpers
+use crate::validation::create_error;
+
+// structs
+use crate::structs::{GraphQLError, LintConfig};
+
+pub fn get_name_pattern(pattern: &str) -> Result<Regex, regex::Error> {
+    match pattern {
+        "PascalCase" => Regex::new(r"^[A-Z][A-Za-z0-9]*$"),
+        "camelCase" => Regex::new(r"^[a-z][A-Za-z0-9]*$"),
+        _ => Regex::new(pattern),
+    }
+}
+
+fn anonymous_operation_error(message: &str, operation: &OperationDefinition) -> GraphQLError {
+    match operation.operation_type() {
+        Some(operation_type) => create_error(message.into(), &operation_type),
+        None => match operation.selection_set() {
+            Some(selection_set) => {
+                let mut error = create_error(message.into(), &selection_set);
+                error.len = 1;
+                error
+            }
+            None => create_error(message.into(), operation),
+        },
+    }
+}
+
+pub fn validate_naming(
+    document: &Document,
+    lint: &LintConfig,
+    name_pattern: Option<&Regex>,
+    operation_names: &mut HashSet<String>,
+) -> Vec<GraphQLError> {
+    let mut errors = vec![];
+    let is_multiple_definitions = document.definitions().count() > 1;
+
+    for definition in document.definitions() {
+        match definition {
+            Definition::OperationDefinition(operation) => {
+                let Some(name) = operation.name() else {
+                    if is_multiple_definitions {
+                        errors.push(anonymous_operation_error(
+                            "GraphQL query must have name when document has multiple definitions",
+                            &operation,
+                        ));
+                    } else if lint.require_operation_name {
+                        errors.push(anonymous_operation_error(
+                            "GraphQL operation must have name",
+                            &operation,
+                        ));
+                    }
+                    continue;
+                };
+                let name_text = name.text().to_string();
+
+                if let Some(regex) = name_pattern {
+                    if !regex.is_match(&name_text) {
+                        errors.push(create_error(
+                            format!(
+                                "Operation name \"{}\" does not match pattern \"{}\"",
+                                name_text,
+                                lint.operation_name_pattern.as_ref().unwrap()
+                            ),
+                            &name,
+                        ));
+                    }
+                }
+
+                if lint.unique_operation_names && !operation_names.insert(name_text.clone()) {
+                    errors.push(create_error(
+                        format!(
+                            "Operation name \"{}\" is already used in this module",
+                            name_text
+                        ),
+                        &name,
+                    ));
+                }
+            }
+            Definition::FragmentDefinition(fragment) => {
+                if !lint.fragment_name_matches_type {
+                    continue;
+                }
+
+                let (Some(name), Some(type_name)) = (
+                    fragment.fragment_name().and_then(|name| name.name()),
+                    fragment
+                        .type_condition()
+                        .and_then(|type_condition| type_condition.named_type())
+                        .and_then(|named_type| named_type.name()),
+                ) else {
+                    continue;
+                };
+
+                let name_text = name.text().to_string();
+                let type_text = type_name.text().to_string();
+                if !name_text.starts_with(&type_text) {
+                    errors.push(create_error(
+                        format!(
+                            "Fragment name \"{}\" must start with its type condition \"{}\"",
+                            name_text, type_text
+                        ),
+                        &name,
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    errors
+}
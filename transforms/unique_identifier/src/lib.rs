@@ -7,6 +7,12 @@ pub struct UniqueIdentifierVisitor {
     pub count: i64,
 }
 
+impl Default for UniqueIdentifierVisitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl UniqueIdentifierVisitor {
     pub fn new() -> Self {
         Self {
@@ -14,12 +20,22 @@ impl UniqueIdentifierVisitor {
             count: 0,
         }
     }
+
+    /// Name of the helper function, suffixed when the visited program
+    /// already uses `identifier`.
+    pub fn unique_name(&self) -> String {
+        if self.count > 0 {
+            format!("{}{}", self.identifier, self.count)
+        } else {
+            self.identifier.clone()
+        }
+    }
 }
 
 impl VisitMut for UniqueIdentifierVisitor {
     fn visit_mut_ident(&mut self, node: &mut Ident) {
         if node.sym.as_str() == self.identifier {
-            self.count = self.count + 1;
+            self.count += 1;
         }
     }
 }